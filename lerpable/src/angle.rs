@@ -0,0 +1,250 @@
+// angles lerp around the circle instead of along the number line, so 350° to 10° goes up
+// through 0° instead of back down through 180°. `Angle` and `Degrees` do this for a whole
+// type, and `#[lerpable(angle(...))]` does it for an f32 or f64 field.
+
+use std::f64::consts::TAU;
+
+use crate::fallible::{impl_try_lerpable_finite, Finite};
+use crate::{
+    lerp_f64, FieldPath, IsLerpingMethod, LerpHow, LerpPlan, LerpPlanKind, LerpReport, Lerpable,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleDirection {
+    Shortest,         // whichever way is closer, counterclockwise if they're opposite
+    Clockwise,        // decreasing
+    CounterClockwise, // increasing
+}
+
+// which way around to go, and how many extra full turns to spin on the way
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnglePath {
+    pub direction: AngleDirection,
+    pub turns: u32,
+    pub full_turn: f64, // TAU for radians, 360 for degrees
+}
+
+impl AnglePath {
+    pub fn radians() -> Self {
+        Self {
+            direction: AngleDirection::Shortest,
+            turns: 0,
+            full_turn: TAU,
+        }
+    }
+
+    pub fn degrees() -> Self {
+        Self {
+            full_turn: 360.0,
+            ..Self::radians()
+        }
+    }
+
+    pub fn with_direction(self, direction: AngleDirection) -> Self {
+        Self { direction, ..self }
+    }
+
+    pub fn with_turns(self, turns: u32) -> Self {
+        Self { turns, ..self }
+    }
+
+    // how far to go from `start` to end up at `end`, positive if counterclockwise
+    pub fn delta(&self, start: f64, end: f64) -> f64 {
+        let full = self.full_turn;
+        let ccw = (end - start).rem_euclid(full);
+        // rem_euclid can round up to a full turn for tiny negative differences
+        let ccw = if ccw >= full { 0.0 } else { ccw };
+        let extra = self.turns as f64 * full;
+        match self.direction {
+            AngleDirection::CounterClockwise => ccw + extra,
+            AngleDirection::Clockwise if ccw == 0.0 => -extra,
+            AngleDirection::Clockwise => ccw - full - extra,
+            AngleDirection::Shortest if ccw > full / 2.0 => ccw - full - extra,
+            AngleDirection::Shortest => ccw + extra,
+        }
+    }
+}
+
+// the float types `lerp_angle` works with
+pub trait LerpAngle: Copy {
+    fn to_f64(self) -> f64;
+    fn from_f64(x: f64) -> Self;
+}
+
+impl LerpAngle for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+}
+
+impl LerpAngle for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+}
+
+// lerps along `path`. the result isn't wrapped, so it can be outside a full turn in between,
+// but pct 0 gives exactly `start` and pct 1 gives exactly `end`.
+pub fn lerp_angle<T: LerpAngle, LerpMethod: IsLerpingMethod>(
+    this: &T,
+    other: &T,
+    pct: &LerpMethod,
+    path: AnglePath,
+) -> T {
+    let (start, end) = (this.to_f64(), other.to_f64());
+    let p = pct.lerp_pct();
+    let value = if p == 1.0 {
+        end
+    } else {
+        lerp_f64(start, start + path.delta(start, end), p)
+    };
+    let noise = pct.lerp_options().noise;
+    if noise == 0.0 {
+        T::from_f64(value)
+    } else {
+        T::from_f64(value + noise)
+    }
+}
+
+// radians, lerped the shortest way around
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Angle(pub f32);
+
+// degrees, lerped the shortest way around
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f32);
+
+macro_rules! impl_lerpable_angle {
+    ($t:ident, $path:expr) => {
+        impl Lerpable for $t {
+            fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                $t(lerp_angle(&self.0, &other.0, pct, $path))
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+            }
+        }
+
+        impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                self.0.is_finite()
+            }
+        }
+
+        impl_try_lerpable_finite!($t);
+    };
+}
+
+impl_lerpable_angle!(Angle, AnglePath::radians());
+impl_lerpable_angle!(Degrees, AnglePath::degrees());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn degrees(start: f64, end: f64, pct: f64, path: AnglePath) -> f64 {
+        lerp_angle(&start, &end, &pct, path)
+    }
+
+    #[test]
+    fn shortest_wraps_across_pi() {
+        let path = AnglePath::radians();
+        // 3 to -3 is 0.28 the short way, through pi
+        let mid = lerp_angle(&3.0, &-3.0, &0.5, path);
+        assert!(close(mid, PI), "{mid}");
+        let mid = lerp_angle(&-3.0, &3.0, &0.5, path);
+        assert!(close(mid, -PI), "{mid}");
+        assert!(close(path.delta(3.0, -3.0), TAU - 6.0));
+        // and across 0 in degrees
+        assert!(close(
+            degrees(350.0, 10.0, 0.5, AnglePath::degrees()),
+            360.0
+        ));
+        assert!(close(degrees(10.0, 350.0, 0.5, AnglePath::degrees()), 0.0));
+    }
+
+    #[test]
+    fn half_turns_go_counterclockwise() {
+        assert_eq!(AnglePath::radians().delta(0.0, PI), PI);
+        assert_eq!(AnglePath::radians().delta(PI, 0.0), PI);
+        assert_eq!(AnglePath::degrees().delta(90.0, 270.0), 180.0);
+        assert_eq!(AnglePath::degrees().delta(270.0, 90.0), 180.0);
+        assert!(close(
+            degrees(270.0, 90.0, 0.5, AnglePath::degrees()),
+            360.0
+        ));
+    }
+
+    #[test]
+    fn directions() {
+        let cw = AnglePath::degrees().with_direction(AngleDirection::Clockwise);
+        let ccw = AnglePath::degrees().with_direction(AngleDirection::CounterClockwise);
+        assert_eq!(cw.delta(10.0, 350.0), -20.0);
+        assert_eq!(cw.delta(350.0, 10.0), -340.0);
+        assert_eq!(ccw.delta(10.0, 350.0), 340.0);
+        assert_eq!(ccw.delta(350.0, 10.0), 20.0);
+        assert!(close(degrees(10.0, 350.0, 0.5, cw), 0.0));
+        assert!(close(degrees(10.0, 350.0, 0.5, ccw), 180.0));
+        // the same angle, or a full turn apart, doesn't move unless there are turns
+        assert_eq!(cw.delta(30.0, 30.0), 0.0);
+        assert_eq!(ccw.delta(30.0, 390.0), 0.0);
+        assert_eq!(cw.with_turns(1).delta(30.0, 30.0), -360.0);
+        assert_eq!(ccw.with_turns(1).delta(30.0, 30.0), 360.0);
+    }
+
+    #[test]
+    fn turns_add_full_spins() {
+        let path = AnglePath::degrees().with_turns(2);
+        assert_eq!(path.delta(0.0, 90.0), 810.0);
+        assert_eq!(path.delta(90.0, 0.0), -810.0);
+        assert!(close(degrees(0.0, 90.0, 0.5, path), 405.0));
+        let cw = path.with_direction(AngleDirection::Clockwise);
+        assert_eq!(cw.delta(0.0, 90.0), -990.0);
+        // but it always ends exactly where it's going
+        assert_eq!(degrees(0.0, 90.0, 1.0, path), 90.0);
+        assert_eq!(degrees(0.0, 90.0, 0.0, path), 0.0);
+        let radians = AnglePath::radians().with_turns(3);
+        assert!(close(radians.delta(0.0, 1.0), 1.0 + 3.0 * TAU));
+        assert_eq!(lerp_angle(&0.1f32, &1.0, &1.0, radians), 1.0);
+    }
+
+    #[test]
+    fn degrees_agree_with_radians() {
+        let pairs = [
+            (0.0, 90.0),
+            (350.0, 10.0),
+            (10.0, 350.0),
+            (-170.0, 170.0),
+            (45.0, 225.0),
+        ];
+        for (a, b) in pairs {
+            for pct in [0.0, 0.2, 0.5, 0.9, 1.0] {
+                let d = Degrees(a).lerpify(&Degrees(b), &pct).0;
+                let r = Angle(a.to_radians())
+                    .lerpify(&Angle(b.to_radians()), &pct)
+                    .0;
+                assert!((d - r.to_degrees()).abs() < 1e-3, "{a} {b} {pct}: {d} {r}");
+            }
+        }
+        assert_eq!(Degrees(350.0).lerpify(&Degrees(10.0), &1.0), Degrees(10.0));
+        assert_eq!(Angle(3.0).lerpify(&Angle(-3.0), &0.0), Angle(3.0));
+    }
+}
@@ -0,0 +1,681 @@
+// colors, which look muddy when their sRGB numbers are lerped directly. each type lerps in
+// its own space, with alpha premultiplied so a transparent side doesn't pull its color in,
+// and fades in through alpha with `lerp_partial`.
+//
+//  - `Srgba` lerps in OKLab, which keeps the lightness even. the modules below lerp it in
+//    another space instead, e.g. `#[lerpable(with = "lerpable::color::oklch")]`.
+//  - `LinearRgba` lerps in linear light, the way light mixes
+//  - `Oklab` lerps in OKLab
+//  - `Oklch` and `Hsva` go around the hue the shortest way, so complementary colors don't
+//    pass through gray. hue isn't premultiplied, and a gray side takes the other's hue.
+//
+// every component is an f32, with 0 to 1 for rgb, alpha, saturation, value, and OKLab's L,
+// and degrees for hue. pct 0 and 1 give back exactly the start and end.
+
+use crate::fallible::{impl_try_lerpable_finite, Finite};
+use crate::{
+    lerp_angle, lerp_f32, with_exact_ends, AnglePath, FieldPath, IsLerpingMethod, LerpHow,
+    LerpPlan, LerpPlanKind, LerpReport, Lerpable,
+};
+
+// below this chroma or saturation, hue doesn't mean anything
+const ACHROMATIC: f32 = 1e-4;
+
+// how far outside 0 to 1 a linear channel can be and still count as in sRGB, for rounding
+const GAMUT_SLACK: f32 = 1e-4;
+
+// sRGB-encoded, like most colors you'll type in
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Srgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct LinearRgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+// on sRGB-encoded values
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Hsva {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+impl Srgba {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+}
+
+impl LinearRgba {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Oklab {
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl Oklch {
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl Hsva {
+    pub fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self { h, s, v, a }
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c.abs() <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c.abs() <= 0.0031308 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * c.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+impl From<Srgba> for LinearRgba {
+    fn from(c: Srgba) -> Self {
+        Self::new(
+            srgb_to_linear(c.r),
+            srgb_to_linear(c.g),
+            srgb_to_linear(c.b),
+            c.a,
+        )
+    }
+}
+
+impl From<LinearRgba> for Srgba {
+    fn from(c: LinearRgba) -> Self {
+        Self::new(
+            linear_to_srgb(c.r),
+            linear_to_srgb(c.g),
+            linear_to_srgb(c.b),
+            c.a,
+        )
+    }
+}
+
+// https://bottosson.github.io/posts/oklab/, with the constants as published
+#[allow(clippy::excessive_precision)]
+impl From<LinearRgba> for Oklab {
+    fn from(c: LinearRgba) -> Self {
+        let l = 0.4122214708 * c.r + 0.5363325363 * c.g + 0.0514459929 * c.b;
+        let m = 0.2119034982 * c.r + 0.6806995451 * c.g + 0.1073969566 * c.b;
+        let s = 0.0883024619 * c.r + 0.2817188376 * c.g + 0.6299787005 * c.b;
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Self::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            c.a,
+        )
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+impl From<Oklab> for LinearRgba {
+    fn from(c: Oklab) -> Self {
+        let l = c.l + 0.3963377774 * c.a + 0.2158037573 * c.b;
+        let m = c.l - 0.1055613458 * c.a - 0.0638541728 * c.b;
+        let s = c.l - 0.0894841775 * c.a - 1.2914855480 * c.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        Self::new(
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+            c.alpha,
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(c: Oklab) -> Self {
+        let h = c.b.atan2(c.a).to_degrees().rem_euclid(360.0);
+        Self::new(c.l, c.a.hypot(c.b), h, c.alpha)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(c: Oklch) -> Self {
+        let (sin, cos) = c.h.to_radians().sin_cos();
+        Self::new(c.l, c.c * cos, c.c * sin, c.alpha)
+    }
+}
+
+impl From<Srgba> for Oklab {
+    fn from(c: Srgba) -> Self {
+        LinearRgba::from(c).into()
+    }
+}
+
+// OKLab can describe colors sRGB can't show, which would give channels outside 0 to 1. so
+// these keep their lightness and hue and lose chroma until they fit, and whatever's left
+// over (like lightness past white) is clamped.
+impl From<Oklab> for Srgba {
+    fn from(c: Oklab) -> Self {
+        let in_gamut = |c: LinearRgba| {
+            [c.r, c.g, c.b]
+                .iter()
+                .all(|x| (-GAMUT_SLACK..=1.0 + GAMUT_SLACK).contains(x))
+        };
+        let with_chroma =
+            |kept: f32| LinearRgba::from(Oklab::new(c.l, c.a * kept, c.b * kept, c.alpha));
+        let linear = if in_gamut(with_chroma(1.0)) {
+            with_chroma(1.0)
+        } else {
+            // how much of the chroma to keep
+            let (mut lo, mut hi) = (0.0, 1.0);
+            for _ in 0..24 {
+                let mid = (lo + hi) / 2.0;
+                if in_gamut(with_chroma(mid)) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            with_chroma(lo)
+        };
+        let [r, g, b] = [linear.r, linear.g, linear.b].map(|x| x.clamp(0.0, 1.0));
+        LinearRgba::new(r, g, b, linear.a).into()
+    }
+}
+
+impl From<Srgba> for Oklch {
+    fn from(c: Srgba) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+impl From<Oklch> for Srgba {
+    fn from(c: Oklch) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+impl From<Srgba> for Hsva {
+    fn from(c: Srgba) -> Self {
+        let max = c.r.max(c.g).max(c.b);
+        let min = c.r.min(c.g).min(c.b);
+        let delta = max - min;
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == c.r {
+            60.0 * ((c.g - c.b) / delta).rem_euclid(6.0)
+        } else if max == c.g {
+            60.0 * ((c.b - c.r) / delta + 2.0)
+        } else {
+            60.0 * ((c.r - c.g) / delta + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        Self::new(h, s, max, c.a)
+    }
+}
+
+impl From<Hsva> for Srgba {
+    fn from(c: Hsva) -> Self {
+        let chroma = c.v * c.s;
+        let h = c.h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = c.v - chroma;
+        Self::new(r + m, g + m, b + m, c.a)
+    }
+}
+
+// lerps `N` components premultiplied by alpha, and then divides it back out. if the alpha
+// in between is 0, there's nothing to divide by, so those are lerped as they are.
+fn lerp_premultiplied<const N: usize>(
+    (this, this_alpha): ([f32; N], f32),
+    (other, other_alpha): ([f32; N], f32),
+    pct: f32,
+) -> ([f32; N], f32) {
+    let alpha = lerp_f32(this_alpha, other_alpha, pct);
+    let components = std::array::from_fn(|i| {
+        if alpha == 0.0 {
+            lerp_f32(this[i], other[i], pct)
+        } else {
+            lerp_f32(this[i] * this_alpha, other[i] * other_alpha, pct) / alpha
+        }
+    });
+    (components, alpha)
+}
+
+// the shortest way around, unless one side is gray
+fn lerp_hue(this: (f32, f32), other: (f32, f32), pct: f32) -> f32 {
+    let ((this_h, this_chroma), (other_h, other_chroma)) = (this, other);
+    let hue = match (this_chroma < ACHROMATIC, other_chroma < ACHROMATIC) {
+        (true, true) | (false, true) => this_h,
+        (true, false) => other_h,
+        (false, false) => lerp_angle(&this_h, &other_h, &pct, AnglePath::degrees()),
+    };
+    hue.rem_euclid(360.0)
+}
+
+// noise from the method (like `GaussianMutation`) for each of the three channels a color
+// lerps in, in that channel's own units. each channel is its own leaf, so they're drawn
+// separately. alpha doesn't get any, so mutating can't make a color vanish. None if there's
+// no noise, so the color doesn't need converting.
+fn channel_noise<LerpMethod: IsLerpingMethod>(
+    pct: &LerpMethod,
+    channels: [&'static str; 3],
+) -> Option<[f32; 3]> {
+    let noise = channels.map(|x| pct.enter_field(x).lerp_options().noise as f32);
+    (noise != [0.0; 3]).then_some(noise)
+}
+
+// the default for every color, for fading in through alpha
+fn faded_alpha<LerpMethod: IsLerpingMethod>(alpha: f32, pct: LerpMethod) -> f32 {
+    alpha * pct.lerp_pct_f32().clamp(0.0, 1.0)
+}
+
+impl LinearRgba {
+    const CHANNELS: [&'static str; 3] = ["r", "g", "b"];
+
+    fn with_noise(&self, [r, g, b]: [f32; 3]) -> Self {
+        Self::new(self.r + r, self.g + g, self.b + b, self.a)
+    }
+
+    fn lerp_inner(&self, other: &Self, pct: f32) -> Self {
+        let ([r, g, b], a) = lerp_premultiplied(
+            ([self.r, self.g, self.b], self.a),
+            ([other.r, other.g, other.b], other.a),
+            pct,
+        );
+        Self::new(r, g, b, a)
+    }
+
+    fn faded<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        Self {
+            a: faded_alpha(self.a, pct),
+            ..*self
+        }
+    }
+}
+
+impl Oklab {
+    const CHANNELS: [&'static str; 3] = ["l", "a", "b"];
+
+    fn with_noise(&self, [l, a, b]: [f32; 3]) -> Self {
+        Self::new(self.l + l, self.a + a, self.b + b, self.alpha)
+    }
+
+    fn lerp_inner(&self, other: &Self, pct: f32) -> Self {
+        let ([l, a, b], alpha) = lerp_premultiplied(
+            ([self.l, self.a, self.b], self.alpha),
+            ([other.l, other.a, other.b], other.alpha),
+            pct,
+        );
+        Self::new(l, a, b, alpha)
+    }
+
+    fn faded<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        Self {
+            alpha: faded_alpha(self.alpha, pct),
+            ..*self
+        }
+    }
+}
+
+impl Oklch {
+    const CHANNELS: [&'static str; 3] = ["l", "c", "h"];
+
+    fn with_noise(&self, [l, c, h]: [f32; 3]) -> Self {
+        let c = (self.c + c).max(0.0);
+        Self::new(self.l + l, c, (self.h + h).rem_euclid(360.0), self.alpha)
+    }
+
+    fn lerp_inner(&self, other: &Self, pct: f32) -> Self {
+        let ([l, c], alpha) = lerp_premultiplied(
+            ([self.l, self.c], self.alpha),
+            ([other.l, other.c], other.alpha),
+            pct,
+        );
+        let h = lerp_hue((self.h, self.c), (other.h, other.c), pct);
+        Self::new(l, c, h, alpha)
+    }
+
+    fn faded<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        Self {
+            alpha: faded_alpha(self.alpha, pct),
+            ..*self
+        }
+    }
+}
+
+impl Hsva {
+    const CHANNELS: [&'static str; 3] = ["h", "s", "v"];
+
+    fn with_noise(&self, [h, s, v]: [f32; 3]) -> Self {
+        Self::new(
+            (self.h + h).rem_euclid(360.0),
+            self.s + s,
+            self.v + v,
+            self.a,
+        )
+    }
+
+    fn lerp_inner(&self, other: &Self, pct: f32) -> Self {
+        let ([s, v], a) = lerp_premultiplied(
+            ([self.s, self.v], self.a),
+            ([other.s, other.v], other.a),
+            pct,
+        );
+        let h = lerp_hue((self.h, self.s), (other.h, other.s), pct);
+        Self::new(h, s, v, a)
+    }
+
+    fn faded<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        Self {
+            a: faded_alpha(self.a, pct),
+            ..*self
+        }
+    }
+}
+
+impl Srgba {
+    const CHANNELS: [&'static str; 3] = Oklab::CHANNELS;
+
+    fn with_noise(&self, noise: [f32; 3]) -> Self {
+        Oklab::from(*self).with_noise(noise).into()
+    }
+
+    fn lerp_inner(&self, other: &Self, pct: f32) -> Self {
+        oklab::lerp_inner(self, other, pct)
+    }
+
+    fn faded<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        Self {
+            a: faded_alpha(self.a, pct),
+            ..*self
+        }
+    }
+}
+
+macro_rules! impl_lerpable_color {
+    ($t:ident, $($component:ident),*) => {
+        impl Lerpable for $t {
+            fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                let p = pct.lerp_pct_f32();
+                let value = with_exact_ends(self, other, p as f64, || self.lerp_inner(other, p));
+                match channel_noise(pct, Self::CHANNELS) {
+                    Some(noise) => value.with_noise(noise),
+                    None => value,
+                }
+            }
+
+            fn lerp_partial<T: IsLerpingMethod>(&self, pct: T) -> Self {
+                self.faded(pct)
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+            }
+        }
+
+        impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                [$(self.$component),*].iter().all(|x| x.is_finite())
+            }
+        }
+
+        impl_try_lerpable_finite!($t);
+    };
+}
+
+impl_lerpable_color!(Srgba, r, g, b, a);
+impl_lerpable_color!(LinearRgba, r, g, b, a);
+impl_lerpable_color!(Oklab, l, a, b, alpha);
+impl_lerpable_color!(Oklch, l, c, h, alpha);
+impl_lerpable_color!(Hsva, h, s, v, a);
+
+// modules for `#[lerpable(with = "...")]` on `Srgba` fields, to lerp them in another space
+macro_rules! srgba_in {
+    ($module:ident, $space:ident) => {
+        pub mod $module {
+            use super::*;
+
+            pub(super) fn lerp_inner(this: &Srgba, other: &Srgba, pct: f32) -> Srgba {
+                $space::from(*this)
+                    .lerp_inner(&$space::from(*other), pct)
+                    .into()
+            }
+
+            pub fn lerpify<T: IsLerpingMethod>(this: &Srgba, other: &Srgba, pct: &T) -> Srgba {
+                let p = pct.lerp_pct_f32();
+                let value = with_exact_ends(this, other, p as f64, || lerp_inner(this, other, p));
+                match channel_noise(pct, $space::CHANNELS) {
+                    Some(noise) => $space::from(value).with_noise(noise).into(),
+                    None => value,
+                }
+            }
+
+            pub fn lerp_partial<T: IsLerpingMethod>(this: &Srgba, pct: T) -> Srgba {
+                this.faded(pct)
+            }
+        }
+    };
+}
+
+srgba_in!(linear, LinearRgba);
+srgba_in!(oklab, Oklab);
+srgba_in!(oklch, Oklch);
+srgba_in!(hsv, Hsva);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    // how far apart two hues are, the short way
+    fn hue_gap(a: f32, b: f32) -> f32 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    #[test]
+    fn hue_goes_the_short_way() {
+        let at = |from: f32, to: f32, pct: f32| {
+            Oklch::new(0.7, 0.1, from, 1.0)
+                .lerpify(&Oklch::new(0.7, 0.1, to, 1.0), &pct)
+                .h
+        };
+        assert!(close(at(30.0, 90.0, 0.5), 60.0));
+        assert!(close(at(0.0, 270.0, 0.5), 315.0));
+        // across the wrap, both ways
+        assert!(hue_gap(at(350.0, 10.0, 0.5), 0.0) < 1e-3);
+        assert!(close(at(10.0, 350.0, 0.25), 5.0));
+        assert!(close(at(350.0, 10.0, 0.75), 5.0));
+        // it stays in 0 to 360
+        for pct in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert!((0.0..360.0).contains(&at(350.0, 10.0, pct)));
+        }
+
+        let hsv = Hsva::new(340.0, 1.0, 1.0, 1.0).lerpify(&Hsva::new(20.0, 1.0, 1.0, 1.0), &0.5);
+        assert!(hue_gap(hsv.h, 0.0) < 1e-3);
+    }
+
+    #[test]
+    fn transparent_ends_dont_tint() {
+        let red = Srgba::rgb(1.0, 0.0, 0.0);
+        let clear_blue = Srgba::new(0.0, 0.0, 1.0, 0.0);
+        for pct in [0.25, 0.5, 0.75] {
+            let mid = red.lerpify(&clear_blue, &pct);
+            assert!(close(mid.r, 1.0) && close(mid.g, 0.0) && close(mid.b, 0.0));
+            assert!(close(mid.a, 1.0 - pct));
+        }
+
+        let mid =
+            LinearRgba::new(0.0, 1.0, 0.0, 0.0).lerpify(&LinearRgba::new(0.2, 0.4, 0.6, 1.0), &0.5);
+        assert!(close(mid.r, 0.2) && close(mid.g, 0.4) && close(mid.b, 0.6));
+        assert!(close(mid.a, 0.5));
+    }
+
+    #[test]
+    fn grays_take_the_other_hue() {
+        let gray = Hsva::new(0.0, 0.0, 0.5, 1.0);
+        let blue = Hsva::new(240.0, 1.0, 1.0, 1.0);
+        assert_eq!(gray.lerpify(&blue, &0.5).h, 240.0);
+        assert_eq!(blue.lerpify(&gray, &0.5).h, 240.0);
+
+        let gray = Oklch::new(0.5, 0.0, 123.0, 1.0);
+        let teal = Oklch::new(0.7, 0.1, 200.0, 1.0);
+        assert_eq!(gray.lerpify(&teal, &0.3).h, 200.0);
+        assert_eq!(teal.lerpify(&gray, &0.3).h, 200.0);
+
+        // with two grays, it keeps the start's
+        let other_gray = Oklch::new(0.9, 0.0, 45.0, 1.0);
+        assert_eq!(gray.lerpify(&other_gray, &0.5).h, 123.0);
+    }
+
+    #[test]
+    fn lerp_partial_fades_in_through_alpha() {
+        let red = Srgba::new(1.0, 0.0, 0.0, 0.8);
+        assert_eq!(red.lerp_partial(0.25), Srgba::new(1.0, 0.0, 0.0, 0.2));
+        assert_eq!(red.lerp_partial(0.0).a, 0.0);
+        assert_eq!(red.lerp_partial(2.0), red);
+        assert_eq!(Oklch::new(0.5, 0.1, 30.0, 1.0).lerp_partial(0.5).alpha, 0.5);
+        assert_eq!(Hsva::new(30.0, 1.0, 1.0, 1.0).lerp_partial(-1.0).a, 0.0);
+
+        // so a color that's only on one side fades in
+        let blue = Srgba::rgb(0.0, 0.0, 1.0);
+        let grown = vec![red].lerpify(&vec![red, blue], &0.75);
+        assert_eq!(grown[1], Srgba::new(0.0, 0.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn stays_in_srgb() {
+        let in_range = |c: Srgba| [c.r, c.g, c.b].iter().all(|x| (0.0..=1.0).contains(x));
+        let colors = [
+            Srgba::rgb(1.0, 0.0, 0.0),
+            Srgba::rgb(0.0, 1.0, 1.0),
+            Srgba::rgb(0.0, 0.0, 1.0),
+            Srgba::rgb(1.0, 1.0, 0.0),
+            Srgba::rgb(0.0, 1.0, 0.0),
+            Srgba::rgb(1.0, 0.0, 1.0),
+        ];
+        for a in colors {
+            for b in colors {
+                for pct in [0.1, 0.25, 0.5, 0.75, 0.9] {
+                    for mid in [
+                        a.lerpify(&b, &pct),
+                        oklch::lerpify(&a, &b, &pct),
+                        hsv::lerpify(&a, &b, &pct),
+                    ] {
+                        assert!(in_range(mid), "{a:?} {b:?} {pct}: {mid:?}");
+                    }
+                }
+            }
+        }
+
+        // too much chroma keeps its hue and lightness
+        let vivid = Oklch::new(0.6, 0.4, 150.0, 1.0);
+        let shown = Srgba::from(vivid);
+        assert!(in_range(shown), "{shown:?}");
+        let back = Oklch::from(shown);
+        assert!(
+            close(back.l, 0.6) && (back.h - 150.0).abs() < 1.0,
+            "{back:?}"
+        );
+        assert!(back.c < 0.4);
+
+        // and colors that fit come back as they were
+        let fits = Srgba::rgb(0.2, 0.4, 0.6);
+        let back = Srgba::from(Oklab::from(fits));
+        assert!(close(back.r, 0.2) && close(back.g, 0.4) && close(back.b, 0.6));
+        // past white is just white
+        let white = Srgba::from(Oklch::new(1.5, 0.0, 0.0, 1.0));
+        assert!(close(white.r, 1.0) && close(white.g, 1.0) && close(white.b, 1.0));
+    }
+
+    #[test]
+    fn mutation_adds_noise_to_each_channel() {
+        use crate::GaussianMutation;
+
+        let teal = Oklab::new(0.6, -0.1, -0.05, 0.5);
+        let mutated = teal.lerpify(&teal, &GaussianMutation::new(0.0, 3, 0.01));
+        let moved = [mutated.l - teal.l, mutated.a - teal.a, mutated.b - teal.b];
+        assert!(moved.iter().all(|x| *x != 0.0), "{moved:?}");
+        // each channel gets its own draw
+        assert!(moved[0] != moved[1] && moved[1] != moved[2], "{moved:?}");
+        assert_eq!(mutated.alpha, teal.alpha);
+        // the same seed gives the same color
+        assert_eq!(
+            mutated,
+            teal.lerpify(&teal, &GaussianMutation::new(0.0, 3, 0.01))
+        );
+
+        let hue = Oklch::new(0.6, 0.1, 359.99, 1.0);
+        let mutated = hue.lerpify(&hue, &GaussianMutation::new(0.0, 1, 5.0));
+        assert!((0.0..360.0).contains(&mutated.h) && mutated.c >= 0.0);
+
+        let gray = Srgba::rgb(0.5, 0.5, 0.5);
+        let mutated = gray.lerpify(&gray, &GaussianMutation::new(0.0, 2, 0.05));
+        assert_ne!(mutated, gray);
+        assert_ne!(
+            oklch::lerpify(&gray, &gray, &GaussianMutation::new(0.0, 2, 0.05)),
+            gray
+        );
+
+        // no noise, no change
+        let off = GaussianMutation::new(0.0, 2, 0.05).with_rate(0.0);
+        assert_eq!(gray.lerpify(&gray, &off), gray);
+        assert_eq!(hsv::lerpify(&gray, &gray, &off), gray);
+    }
+}
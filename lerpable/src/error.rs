@@ -0,0 +1,87 @@
+use std::fmt;
+
+use crate::{FieldPath, FieldPathSegment};
+
+// what went wrong, and where
+#[derive(Clone, Debug, PartialEq)]
+pub struct LerpError {
+    pub path: FieldPath,
+    pub reason: LerpErrorReason,
+}
+
+impl LerpError {
+    pub fn new(reason: LerpErrorReason) -> Self {
+        Self {
+            path: FieldPath::root(),
+            reason,
+        }
+    }
+
+    // on the way back out of a struct field or enum variant, puts it in front of the path
+    pub fn in_field(self, name: &'static str) -> Self {
+        self.within(FieldPathSegment::Field(name))
+    }
+
+    // same, for the elements of a Vec
+    pub fn in_index(self, i: usize) -> Self {
+        self.within(FieldPathSegment::Index(i))
+    }
+
+    fn within(self, segment: FieldPathSegment) -> Self {
+        let mut path = FieldPath::root();
+        path.push(segment);
+        for segment in self.path.segments() {
+            path.push(segment.clone());
+        }
+        Self { path, ..self }
+    }
+}
+
+impl fmt::Display for LerpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{}: {}", self.path, self.reason)
+        }
+    }
+}
+
+impl std::error::Error for LerpError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LerpErrorReason {
+    // an integer lerp landed outside its type, with `Overflow::Checked`
+    Overflow {
+        type_name: &'static str,
+    },
+    // a float was NaN or infinite, going in or coming out
+    NonFinite {
+        type_name: &'static str,
+    },
+    // the two sides of an enum were different variants, and it would have stepped
+    VariantMismatch {
+        from: &'static str,
+        to: &'static str,
+    },
+    // Vecs of different lengths, with `StrictLengths`
+    LengthMismatch {
+        from: usize,
+        to: usize,
+    },
+}
+
+impl fmt::Display for LerpErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LerpErrorReason::Overflow { type_name } => write!(f, "out of range for {}", type_name),
+            LerpErrorReason::NonFinite { type_name } => write!(f, "{} isn't finite", type_name),
+            LerpErrorReason::VariantMismatch { from, to } => {
+                write!(f, "can't lerp from variant {} to {}", from, to)
+            }
+            LerpErrorReason::LengthMismatch { from, to } => {
+                write!(f, "can't lerp from length {} to {}", from, to)
+            }
+        }
+    }
+}
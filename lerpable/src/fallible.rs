@@ -0,0 +1,336 @@
+// lerping that fails instead of quietly doing something reasonable, for checking keyframes
+// before they're used. `#[lerpable(fallible)]` derives `TryLerpable` alongside `Lerpable`.
+
+use crate::{
+    try_lerp_int, IsLerpingMethod, LerpError, LerpErrorReason, LerpOptions, Lerpable, Overflow,
+};
+
+pub trait TryLerpable: Lerpable {
+    // like `lerpify`, but errors on NaN or infinite floats, enum variants that would step,
+    // integers out of range with `Overflow::Checked`, and Vec lengths with `StrictLengths`.
+    // the default never fails, for types where there's nothing to check.
+    fn try_lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Result<Self, LerpError> {
+        Ok(self.lerpify(other, pct))
+    }
+}
+
+// makes `try_lerpify` error on Vecs of different lengths, instead of growing or shrinking
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrictLengths<M: IsLerpingMethod> {
+    inner: M,
+}
+
+impl<M: IsLerpingMethod> StrictLengths<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for StrictLengths<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.inner.has_lerp_stepped()
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        Self::new(self.inner.with_lerp_pct(pct))
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self::new(self.inner.enter_field(name))
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self::new(self.inner.enter_index(i))
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions {
+            strict_lengths: true,
+            ..self.inner.lerp_options()
+        }
+    }
+}
+
+// for types made of floats, whether they're all numbers
+pub(crate) trait Finite {
+    fn is_finite(&self) -> bool;
+}
+
+impl Finite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+// `try_lerpify` errors if either side, or the result, has a NaN or infinity in it
+macro_rules! impl_try_lerpable_finite {
+    ($t:ty) => {
+        impl $crate::TryLerpable for $t {
+            fn try_lerpify<T: $crate::IsLerpingMethod>(
+                &self,
+                other: &Self,
+                pct: &T,
+            ) -> Result<Self, $crate::LerpError> {
+                use $crate::fallible::Finite;
+                let value = $crate::Lerpable::lerpify(self, other, pct);
+                if self.is_finite() && other.is_finite() && value.is_finite() {
+                    Ok(value)
+                } else {
+                    Err($crate::LerpError::new($crate::LerpErrorReason::NonFinite {
+                        type_name: stringify!($t),
+                    }))
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_try_lerpable_finite;
+
+macro_rules! impl_try_lerpable_int {
+    ($t:ty) => {
+        impl TryLerpable for $t {
+            fn try_lerpify<T: IsLerpingMethod>(
+                &self,
+                other: &Self,
+                pct: &T,
+            ) -> Result<Self, LerpError> {
+                try_lerp_int(*self, *other, pct)
+            }
+        }
+    };
+}
+
+impl_try_lerpable_finite!(f32);
+impl_try_lerpable_finite!(f64);
+impl_try_lerpable_int!(usize);
+impl_try_lerpable_int!(u8);
+impl_try_lerpable_int!(u16);
+impl_try_lerpable_int!(u32);
+impl_try_lerpable_int!(u64);
+impl_try_lerpable_int!(u128);
+impl_try_lerpable_int!(isize);
+impl_try_lerpable_int!(i8);
+impl_try_lerpable_int!(i16);
+impl_try_lerpable_int!(i32);
+impl_try_lerpable_int!(i64);
+impl_try_lerpable_int!(i128);
+
+impl TryLerpable for char {
+    fn try_lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Result<Self, LerpError> {
+        let code = try_lerp_int(*self as u32, *other as u32, pct)?;
+        if code > char::MAX as u32 && pct.lerp_options().overflow == Overflow::Checked {
+            return Err(LerpError::new(LerpErrorReason::Overflow {
+                type_name: "char",
+            }));
+        }
+        Ok(self.lerpify(other, pct))
+    }
+}
+
+impl<T: TryLerpable> TryLerpable for Option<T> {
+    fn try_lerpify<LerpMethod: IsLerpingMethod>(
+        &self,
+        other: &Self,
+        method: &LerpMethod,
+    ) -> Result<Self, LerpError> {
+        match (self, other) {
+            (Some(this), Some(other)) => Ok(Some(this.try_lerpify(other, method)?)),
+            _ => Ok(crate::step(self, other, method)),
+        }
+    }
+}
+
+impl TryLerpable for bool {}
+
+impl TryLerpable for String {}
+
+impl<T: TryLerpable + Clone> TryLerpable for Vec<T> {
+    fn try_lerpify<LerpMethod: IsLerpingMethod>(
+        &self,
+        other: &Self,
+        method: &LerpMethod,
+    ) -> Result<Self, LerpError> {
+        if self.len() != other.len() && method.lerp_options().strict_lengths {
+            return Err(LerpError::new(LerpErrorReason::LengthMismatch {
+                from: self.len(),
+                to: other.len(),
+            }));
+        }
+        if self.is_empty() || other.is_empty() {
+            return Ok(self.clone());
+        }
+        try_lerp_vecs(self, other, method)
+    }
+}
+
+// errors on anything in `value` that `try_lerpify` would, like a NaN, by lerping it to itself.
+// the fallible derive runs this on `with` and `angle` fields, which don't go through
+// `try_lerpify`.
+pub fn try_check<T: TryLerpable>(value: T) -> Result<T, LerpError> {
+    value.try_lerpify(&value, &0.0).map(|_| value)
+}
+
+// like lerp_vecs, but stops at the first element that fails, and says which one it was
+pub fn try_lerp_vecs<T, LerpMethod>(
+    this: &[T],
+    other: &[T],
+    pct: &LerpMethod,
+) -> Result<Vec<T>, LerpError>
+where
+    T: Clone + TryLerpable,
+    LerpMethod: IsLerpingMethod,
+{
+    let mut v = vec![];
+    let this_len = this.len();
+    let other_len = other.len();
+    let count = crate::lerp_count(this_len, other_len, pct);
+    for i in 0..count {
+        let result = match (i >= this_len, i >= other_len) {
+            (true, true) => unreachable!(),
+            (true, false) => other[i].lerp_partial(pct.partial_lerp_pct(i, count)),
+            (false, true) => this[i].lerp_partial(pct.partial_lerp_pct(i, count)),
+            (false, false) => this[i]
+                .try_lerpify(&other[i], &pct.enter_index(i))
+                .map_err(|e| e.in_index(i))?,
+        };
+        v.push(result);
+    }
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Angle, Degrees, Hsva, LinearRgba, Mat3, Mat4, Oklab, Oklch, Quaternion, Rotation2, Rotor3,
+        Srgba, Transform2, Transform3,
+    };
+    use std::fmt::Debug;
+
+    // `start` to `end` is fine, but with a NaN or infinity put in either side by `poison`,
+    // it's an error, even at the ends where it would just give back the other side
+    fn check<T: TryLerpable + Clone + PartialEq + Debug>(
+        type_name: &'static str,
+        start: T,
+        end: T,
+        poison: fn(&mut T, f32),
+    ) {
+        let ok = start.try_lerpify(&end, &0.5);
+        assert_eq!(ok, Ok(start.lerpify(&end, &0.5)), "{type_name}");
+        for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let mut broken = end.clone();
+            poison(&mut broken, bad);
+            for (a, b, pct) in [
+                (&start, &broken, 0.5),
+                (&broken, &start, 0.5),
+                (&start, &broken, 0.0),
+                (&broken, &start, 1.0),
+            ] {
+                let err = a.try_lerpify(b, &pct).unwrap_err();
+                assert_eq!(err.reason, LerpErrorReason::NonFinite { type_name });
+            }
+        }
+    }
+
+    #[test]
+    fn try_check_finds_what_try_lerpify_would() {
+        assert_eq!(try_check(vec![1.0f32, 2.0]), Ok(vec![1.0, 2.0]));
+        let err = try_check(vec![1.0f32, f32::NAN]).unwrap_err();
+        assert_eq!(err.reason, LerpErrorReason::NonFinite { type_name: "f32" });
+        assert_eq!(err.path.to_string(), "1");
+        assert!(try_check(Some(f64::INFINITY)).is_err());
+        assert_eq!(try_check("fine".to_owned()), Ok("fine".to_owned()));
+    }
+
+    #[test]
+    fn non_finite_components_are_errors() {
+        check("f32", 0.0f32, 1.0, |x, bad| *x = bad);
+        check("f64", 0.0f64, 1.0, |x, bad| *x = bad as f64);
+        check("Angle", Angle(3.0), Angle(-3.0), |x, bad| x.0 = bad);
+        check("Degrees", Degrees(350.0), Degrees(10.0), |x, bad| x.0 = bad);
+        check(
+            "Srgba",
+            Srgba::rgb(1.0, 0.0, 0.0),
+            Srgba::rgb(0.0, 0.0, 1.0),
+            |x, bad| x.g = bad,
+        );
+        check(
+            "LinearRgba",
+            LinearRgba::new(1.0, 0.0, 0.0, 1.0),
+            LinearRgba::new(0.0, 0.0, 1.0, 0.5),
+            |x, bad| x.a = bad,
+        );
+        check(
+            "Oklab",
+            Oklab::new(0.5, 0.1, 0.0, 1.0),
+            Oklab::new(0.7, 0.0, -0.1, 1.0),
+            |x, bad| x.b = bad,
+        );
+        check(
+            "Oklch",
+            Oklch::new(0.5, 0.1, 30.0, 1.0),
+            Oklch::new(0.7, 0.1, 200.0, 1.0),
+            |x, bad| x.h = bad,
+        );
+        check(
+            "Hsva",
+            Hsva::new(0.0, 0.0, 0.5, 1.0),
+            Hsva::new(120.0, 1.0, 1.0, 1.0),
+            |x, bad| x.h = bad,
+        );
+        check(
+            "Quaternion",
+            Quaternion::IDENTITY,
+            Quaternion::from_axis_angle([0.0, 1.0, 0.0], 1.0),
+            |x, bad| x.x = bad,
+        );
+        check(
+            "Rotor3",
+            Rotor3::IDENTITY,
+            Rotor3::from_angle_plane(1.0, [1.0, 0.0, 0.0]),
+            |x, bad| x.yz = bad,
+        );
+        check(
+            "Rotation2",
+            Rotation2::IDENTITY,
+            Rotation2::from_angle(1.0),
+            |x, bad| x.cos = bad,
+        );
+        check(
+            "Transform2",
+            Transform2::IDENTITY,
+            Transform2::new([1.0, 2.0], Rotation2::from_angle(1.0), [2.0, 3.0]),
+            |x, bad| x.shear = bad,
+        );
+        check(
+            "Transform3",
+            Transform3::IDENTITY,
+            Transform3::new([1.0, 2.0, 3.0], Quaternion::IDENTITY, [2.0; 3]),
+            |x, bad| x.translation[0] = bad,
+        );
+        check("Mat3", Mat3::IDENTITY, Mat3::IDENTITY, |x, bad| {
+            x.0[1][0] = bad
+        });
+        check("Mat4", Mat4::IDENTITY, Mat4::IDENTITY, |x, bad| {
+            x.0[3][3] = bad
+        });
+    }
+}
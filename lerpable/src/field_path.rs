@@ -0,0 +1,381 @@
+use std::{fmt, str::FromStr, sync::Arc};
+
+use crate::IsLerpingMethod;
+
+// where in a nested value a lerp is happening. the derive hands these down through
+// `IsLerpingMethod::enter_field`, and `lerp_vecs` through `IsLerpingMethod::enter_index`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldPathSegment {
+    Field(&'static str), // struct fields, enum variants, and "0" for tuple fields
+    Index(usize),        // position in a Vec
+}
+
+impl fmt::Display for FieldPathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldPathSegment::Field(name) => write!(f, "{}", name),
+            FieldPathSegment::Index(i) => write!(f, "{}", i),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath(Vec<FieldPathSegment>);
+
+impl FieldPath {
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn segments(&self) -> &[FieldPathSegment] {
+        &self.0
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn push(&mut self, segment: FieldPathSegment) {
+        self.0.push(segment)
+    }
+
+    pub fn field(&self, name: &'static str) -> Self {
+        let mut c = self.clone();
+        c.push(FieldPathSegment::Field(name));
+        c
+    }
+
+    pub fn index(&self, i: usize) -> Self {
+        let mut c = self.clone();
+        c.push(FieldPathSegment::Index(i));
+        c
+    }
+}
+
+// prints as `points.3.color`
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PatternSegment {
+    Exact(String),
+    AnyOne,  // *
+    AnyMany, // **, including none
+}
+
+impl PatternSegment {
+    fn matches(&self, segment: &FieldPathSegment) -> bool {
+        match (self, segment) {
+            (PatternSegment::AnyOne, _) | (PatternSegment::AnyMany, _) => true,
+            (PatternSegment::Exact(s), FieldPathSegment::Field(name)) => s == name,
+            (PatternSegment::Exact(s), FieldPathSegment::Index(i)) => s.parse() == Ok(*i),
+        }
+    }
+}
+
+// a glob over field paths, written the same way `FieldPath` prints.
+// `*` matches one segment and `**` matches any number of them, so
+// `**.color` is every field named color, and `points.*.x` is the x of every point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldPattern(Vec<PatternSegment>);
+
+impl FieldPattern {
+    pub fn matches(&self, path: &FieldPath) -> bool {
+        Self::matches_from(&self.0, path.segments())
+    }
+
+    fn matches_from(pattern: &[PatternSegment], path: &[FieldPathSegment]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((PatternSegment::AnyMany, rest)) => {
+                (0..=path.len()).any(|skip| Self::matches_from(rest, &path[skip..]))
+            }
+            Some((first, rest)) => match path.split_first() {
+                Some((segment, path_rest)) => {
+                    first.matches(segment) && Self::matches_from(rest, path_rest)
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+impl FromStr for FieldPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments = s
+            .split('.')
+            .map(|segment| match segment.trim() {
+                "" => Err(format!("empty segment in field pattern {:?}", s)),
+                "*" => Ok(PatternSegment::AnyOne),
+                "**" => Ok(PatternSegment::AnyMany),
+                exact => Ok(PatternSegment::Exact(exact.to_owned())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(FieldPattern(segments))
+    }
+}
+
+// what to do with the fields a pattern matches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathOverride {
+    Lerp,  // the usual
+    Step,  // switch from start to end at the halfway point
+    Start, // always keep the start value
+    End,   // always take the end value
+}
+
+impl FromStr for PathOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "lerp" => Ok(PathOverride::Lerp),
+            "step" => Ok(PathOverride::Step),
+            "start" => Ok(PathOverride::Start),
+            "end" => Ok(PathOverride::End),
+            other => Err(format!("unknown path override {:?}", other)),
+        }
+    }
+}
+
+// rules are checked in order and the last match wins. a field that doesn't match anything
+// does whatever its parent was doing, so `**.color = step` also steps `color.r`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathOverrideTable {
+    rules: Vec<(FieldPattern, PathOverride)>,
+}
+
+impl PathOverrideTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pattern: &str, how: PathOverride) -> Result<(), String> {
+        self.rules.push((pattern.parse()?, how));
+        Ok(())
+    }
+
+    pub fn with(mut self, pattern: &str, how: PathOverride) -> Result<Self, String> {
+        self.insert(pattern, how)?;
+        Ok(self)
+    }
+
+    pub fn lookup(&self, path: &FieldPath) -> Option<PathOverride> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.matches(path))
+            .map(|(_, how)| *how)
+    }
+}
+
+// lines like `**.color = step`, blank lines and `#` comments are ignored
+impl FromStr for PathOverrideTable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table = PathOverrideTable::new();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (pattern, how) = line
+                .split_once('=')
+                .ok_or_else(|| format!("expected `pattern = override`, got {:?}", line))?;
+            table.insert(pattern.trim(), how.parse()?)?;
+        }
+        Ok(table)
+    }
+}
+
+// a lerping method that keeps track of where it is, and applies a `PathOverrideTable`
+// to the fields it visits.
+#[derive(Clone, Debug)]
+pub struct PathOverrides {
+    pct: f64,
+    path: FieldPath,
+    table: Arc<PathOverrideTable>,
+    active: PathOverride,
+}
+
+impl PathOverrides {
+    pub fn new(table: PathOverrideTable, pct: f64) -> Self {
+        let table = Arc::new(table);
+        let path = FieldPath::root();
+        let active = table.lookup(&path).unwrap_or(PathOverride::Lerp);
+        Self {
+            pct,
+            path,
+            table,
+            active,
+        }
+    }
+
+    pub fn path(&self) -> &FieldPath {
+        &self.path
+    }
+
+    pub fn active(&self) -> PathOverride {
+        self.active
+    }
+
+    fn entered(&self, path: FieldPath) -> Self {
+        let active = self.table.lookup(&path).unwrap_or(self.active);
+        Self {
+            pct: self.pct,
+            path,
+            table: self.table.clone(),
+            active,
+        }
+    }
+}
+
+impl IsLerpingMethod for PathOverrides {
+    fn has_lerp_stepped(&self) -> bool {
+        match self.active {
+            PathOverride::Lerp | PathOverride::Step => self.pct > 0.5,
+            PathOverride::Start => false,
+            PathOverride::End => true,
+        }
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.lerp_pct() * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        match self.active {
+            PathOverride::Lerp => self.pct,
+            PathOverride::Step | PathOverride::Start | PathOverride::End => {
+                if self.has_lerp_stepped() {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.pct = pct;
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        self.entered(self.path.field(name))
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        self.entered(self.path.index(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(s: &str) -> FieldPath {
+        let mut p = FieldPath::root();
+        for segment in s.split('.').filter(|s| !s.is_empty()) {
+            match segment.parse() {
+                Ok(i) => p.push(FieldPathSegment::Index(i)),
+                Err(_) => p.push(FieldPathSegment::Field(Box::leak(segment.into()))),
+            }
+        }
+        p
+    }
+
+    fn matches(pattern: &str, p: &str) -> bool {
+        pattern.parse::<FieldPattern>().unwrap().matches(&path(p))
+    }
+
+    #[test]
+    fn one_star_is_one_segment() {
+        assert!(matches("points.*.x", "points.3.x"));
+        assert!(matches("points.*.x", "points.first.x"));
+        assert!(!matches("points.*.x", "points.x"));
+        assert!(!matches("points.*.x", "points.1.2.x"));
+        assert!(matches("*", "color"));
+        assert!(!matches("*", ""));
+    }
+
+    #[test]
+    fn two_stars_can_be_no_segments() {
+        assert!(matches("**.color", "color"));
+        assert!(matches("**.color", "a.b.3.color"));
+        assert!(!matches("**.color", "color.r"));
+        assert!(matches("points.**.x", "points.x"));
+        assert!(matches("points.**.x", "points.1.inner.x"));
+        assert!(matches("**", ""));
+        assert!(matches("**", "a.1.b"));
+        assert!(matches("a.**", "a"));
+    }
+
+    #[test]
+    fn index_segments() {
+        assert!(matches("points.3", "points.3"));
+        assert!(!matches("points.3", "points.4"));
+        // parsed as a number, so leading zeros still match
+        assert!(matches("points.03", "points.3"));
+        assert!(matches("**.0.x", "points.0.x"));
+        assert!(!matches("points.x", "points.0"));
+    }
+
+    #[test]
+    fn bad_patterns() {
+        assert!("a..b".parse::<FieldPattern>().is_err());
+        assert!("".parse::<FieldPattern>().is_err());
+        assert!("a = sideways".parse::<PathOverrideTable>().is_err());
+        assert!("just a pattern".parse::<PathOverrideTable>().is_err());
+    }
+
+    #[test]
+    fn last_match_wins() {
+        let table: PathOverrideTable = "
+            # everything steps, except points, and point 0 keeps its start
+            ** = step
+            points.** = lerp
+            points.0 = start
+            "
+        .parse()
+        .unwrap();
+        assert_eq!(table.lookup(&path("color")), Some(PathOverride::Step));
+        assert_eq!(table.lookup(&path("points.1")), Some(PathOverride::Lerp));
+        assert_eq!(table.lookup(&path("points.0")), Some(PathOverride::Start));
+        // points.0.x only matches the first two, so the later of those wins
+        assert_eq!(table.lookup(&path("points.0.x")), Some(PathOverride::Lerp));
+
+        // and the order matters, a catch-all at the end covers everything
+        let table = table.with("**", PathOverride::End).unwrap();
+        assert_eq!(table.lookup(&path("points.0")), Some(PathOverride::End));
+
+        assert_eq!(PathOverrideTable::new().lookup(&path("a")), None);
+    }
+
+    #[test]
+    fn unmatched_fields_follow_their_parent() {
+        let table = PathOverrideTable::new()
+            .with("**.color", PathOverride::Step)
+            .unwrap();
+        let method = PathOverrides::new(table, 0.25);
+        assert_eq!(method.active(), PathOverride::Lerp);
+        let color = method.enter_field("shape").enter_field("color");
+        assert_eq!(color.active(), PathOverride::Step);
+        assert_eq!(color.enter_field("r").active(), PathOverride::Step);
+        assert_eq!(color.enter_field("r").lerp_pct(), 0.0);
+        assert_eq!(method.enter_index(2).active(), PathOverride::Lerp);
+        assert_eq!(method.enter_index(2).path(), &path("2"));
+    }
+}
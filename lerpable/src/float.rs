@@ -0,0 +1,255 @@
+// precise float lerping. `(1 - pct) * start + pct * end` can miss `end` at pct 1, isn't
+// always monotonic, and gives NaN for infinite endpoints even at 0 or 1.
+//
+// what this does instead:
+//  - pct 0 gives exactly `start`, and pct 1 gives exactly `end`, even if the other is infinite
+//  - it never goes backwards as pct increases (or forwards, if end < start)
+//  - equal endpoints give that value back for any pct
+//  - NaN in start, end, or pct gives NaN
+//  - an infinite pct goes off to the infinity in that direction
+//  - with one infinite endpoint, the result is that infinity, with the sign flipped past the
+//    other endpoint. opposite infinities give NaN between them.
+//
+// `lerp_f32` does the same thing in single precision, for f32s. it matches going through
+// `lerp_f64` and back exactly at pct 0 and 1, with equal endpoints, and for NaN and
+// infinities. in between, it can be off from that by the last bit or two.
+
+use crate::{IsLerpingMethod, LerpOptions};
+
+macro_rules! impl_precise_lerp {
+    ($name:ident, $t:ty) => {
+        pub fn $name(start: $t, end: $t, pct: $t) -> $t {
+            // a * b + c, with a single rounding if the target has FMA. the software fallback
+            // is exact too, but slow, so without FMA it's two roundings.
+            #[inline]
+            fn mul_add(a: $t, b: $t, c: $t) -> $t {
+                #[cfg(target_feature = "fma")]
+                {
+                    a.mul_add(b, c)
+                }
+                #[cfg(not(target_feature = "fma"))]
+                {
+                    a * b + c
+                }
+            }
+
+            if pct.is_nan() || start.is_nan() || end.is_nan() {
+                return <$t>::NAN;
+            }
+            if pct == 0.0 || start == end {
+                return start;
+            }
+            if pct == 1.0 {
+                return end;
+            }
+
+            if start.is_infinite() || end.is_infinite() {
+                return match (start.is_infinite(), end.is_infinite()) {
+                    (true, true) => <$t>::NAN, // they're not equal, so it's -inf to inf
+                    (true, false) => start * (1.0 - pct).signum(),
+                    _ => end * pct.signum(),
+                };
+            }
+            if pct.is_infinite() {
+                return (end - start) * pct;
+            }
+
+            // crossing zero, the two products can't cancel badly, and this is exact at the ends
+            if (start <= 0.0 && end >= 0.0) || (start >= 0.0 && end <= 0.0) {
+                return mul_add(pct, end, (1.0 - pct) * start);
+            }
+
+            // start + pct * (end - start) is monotonic, but can overshoot `end` by rounding,
+            // so clamp it to the side of `end` it should be on
+            let x = mul_add(pct, end - start, start);
+            if (pct > 1.0) == (end > start) {
+                x.max(end)
+            } else {
+                x.min(end)
+            }
+        }
+    };
+}
+
+impl_precise_lerp!(lerp_f64, f64);
+impl_precise_lerp!(lerp_f32, f32);
+
+// how numbers are spaced between the ends, see `LerpOptions::spacing`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spacing {
+    Linear,
+    Geometric, // evenly in log space, for zoom levels, frequencies, and scales
+}
+
+// lerps in log space, so 1 to 100 passes 10 halfway. exact at pct 0 and 1. if both are
+// negative, it's the same on their magnitudes. with a zero, mixed signs, or NaN, there's no
+// log space to lerp in, so it falls back to `lerp_f64`.
+pub fn lerp_geometric(start: f64, end: f64, pct: f64) -> f64 {
+    if pct == 0.0 || start == end {
+        return start;
+    }
+    if pct == 1.0 {
+        return end;
+    }
+    if start > 0.0 && end > 0.0 {
+        lerp_f64(start.ln(), end.ln(), pct).exp()
+    } else if start < 0.0 && end < 0.0 {
+        -lerp_f64((-start).ln(), (-end).ln(), pct).exp()
+    } else {
+        lerp_f64(start, end, pct)
+    }
+}
+
+// how far from `start` to `end` `lerp_geometric` is at `pct`, as a linear pct, so integers
+// can be spaced the same way
+pub(crate) fn geometric_pct(start: f64, end: f64, pct: f64) -> f64 {
+    if start == end {
+        pct
+    } else {
+        (lerp_geometric(start, end, pct) - start) / (end - start)
+    }
+}
+
+// sets the spacing for numbers, and passes everything else through
+#[derive(Clone, Debug, PartialEq)]
+pub struct Geometric<M: IsLerpingMethod> {
+    inner: M,
+}
+
+impl<M: IsLerpingMethod> Geometric<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for Geometric<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.inner.has_lerp_stepped()
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        Self::new(self.inner.with_lerp_pct(pct))
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self::new(self.inner.enter_field(name))
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self::new(self.inner.enter_index(i))
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions {
+            spacing: Spacing::Geometric,
+            ..self.inner.lerp_options()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDS: [(f64, f64); 9] = [
+        (0.0, 1.0),
+        (1.0, 0.0),
+        (-3.0, 7.0),
+        (0.1, 0.3),
+        (1e16, 1e16 + 4.0),
+        (-1e300, 1e300),
+        (f64::MAX, -f64::MAX),
+        (1.0, 1.0 + f64::EPSILON),
+        (5e-324, -5e-324),
+    ];
+
+    #[test]
+    fn endpoints_are_exact() {
+        for (start, end) in ENDS {
+            assert_eq!(lerp_f64(start, end, 0.0), start);
+            assert_eq!(lerp_f64(start, end, 1.0), end);
+            let (start, end) = (start as f32, end as f32);
+            assert_eq!(lerp_f32(start, end, 0.0), start);
+            assert_eq!(lerp_f32(start, end, 1.0), end);
+        }
+        assert_eq!(lerp_f64(2.5, 2.5, 0.3), 2.5);
+        assert_eq!(lerp_f64(2.5, 2.5, 1e10), 2.5);
+        assert_eq!(lerp_geometric(1.0, 100.0, 1.0), 100.0);
+        assert_eq!(lerp_geometric(-1.0, -100.0, 0.0), -1.0);
+    }
+
+    #[test]
+    fn never_goes_backwards() {
+        // lots of pcts, including just under 1, and past both ends
+        let mut pcts: Vec<f64> = (-100..=200).map(|i| i as f64 / 100.0).collect();
+        pcts.extend((1..60).map(|i| 1.0 - 2f64.powi(-i)));
+        pcts.extend((1..60).map(|i| 2f64.powi(-i)));
+        pcts.sort_by(f64::total_cmp);
+
+        for (start, end) in ENDS {
+            let values: Vec<f64> = pcts.iter().map(|p| lerp_f64(start, end, *p)).collect();
+            for (w, p) in values.windows(2).zip(pcts.windows(2)) {
+                let ok = if end >= start {
+                    w[0] <= w[1]
+                } else {
+                    w[0] >= w[1]
+                };
+                assert!(ok, "{start} to {end} at {p:?}: {w:?}");
+            }
+
+            let (start, end) = (start as f32, end as f32);
+            if start.is_infinite() || end.is_infinite() {
+                continue;
+            }
+            let values: Vec<f32> = pcts
+                .iter()
+                .map(|p| lerp_f32(start, end, *p as f32))
+                .collect();
+            for w in values.windows(2) {
+                let ok = if end >= start {
+                    w[0] <= w[1]
+                } else {
+                    w[0] >= w[1]
+                };
+                assert!(ok, "{start} to {end}: {w:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stays_between_the_ends() {
+        for (start, end) in ENDS {
+            let (lo, hi) = (start.min(end), start.max(end));
+            for i in 0..=1000 {
+                let x = lerp_f64(start, end, i as f64 / 1000.0);
+                assert!(lo <= x && x <= hi, "{start} to {end}: {x}");
+            }
+        }
+    }
+
+    #[test]
+    fn nan_and_infinities() {
+        assert!(lerp_f64(f64::NAN, 1.0, 0.0).is_nan());
+        assert!(lerp_f64(0.0, 1.0, f64::NAN).is_nan());
+        assert!(lerp_f64(f64::NEG_INFINITY, f64::INFINITY, 0.5).is_nan());
+        assert_eq!(lerp_f64(f64::INFINITY, 1.0, 1.0), 1.0);
+        assert_eq!(lerp_f64(f64::INFINITY, 1.0, 0.5), f64::INFINITY);
+        assert_eq!(lerp_f64(f64::INFINITY, 1.0, 2.0), f64::NEG_INFINITY);
+        assert_eq!(lerp_f64(1.0, f64::INFINITY, 0.0), 1.0);
+        assert_eq!(lerp_f64(1.0, f64::INFINITY, -0.5), f64::NEG_INFINITY);
+        assert_eq!(lerp_f64(0.0, 1.0, f64::INFINITY), f64::INFINITY);
+        assert_eq!(lerp_f64(0.0, 1.0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,556 @@
+// lerping methods for combining two parents in a genetic algorithm. they're all driven by a
+// seeded `LerpRng` that forks on every field and index, so the same seed and the same
+// parents always give the same child. to save one, keep its `state()` along with the
+// settings it was made with, and rebuild it with `from_state`.
+
+use crate::{IsLerpingMethod, LerpOptions, LerpRng, Lerpable};
+
+fn pick(stepped: bool) -> f64 {
+    if stepped {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+// each leaf independently takes the value from one parent. `bias` is the chance of taking
+// `other`'s value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniformCrossover {
+    pub(crate) rng: LerpRng,
+    bias: f64,
+}
+
+impl UniformCrossover {
+    pub fn new(seed: u64) -> Self {
+        Self::from_state(LerpRng::new(seed).state())
+    }
+
+    pub fn from_state(state: u64) -> Self {
+        Self {
+            rng: LerpRng::from_state(state),
+            bias: 0.5,
+        }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.rng.state()
+    }
+
+    pub fn with_bias(mut self, bias: f64) -> Self {
+        self.bias = bias;
+        self
+    }
+}
+
+impl IsLerpingMethod for UniformCrossover {
+    fn has_lerp_stepped(&self) -> bool {
+        self.rng.peek_f64() < self.bias
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.lerp_pct() * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        pick(self.has_lerp_stepped())
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        self.clone().with_bias(pct)
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork_str(name);
+        c
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork(i as u64);
+        c
+    }
+}
+
+// BLX-alpha: each numeric leaf is drawn uniformly from the range between the parents,
+// stretched by `alpha` of its width on each side. other leaves step past the halfway point
+// of the draw, so they pick a parent at random. `with_lerp_pct` moves the middle of the
+// range from 0.5 to that pct, so things like `fade` still go from one side to the other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlendCrossover {
+    pub(crate) rng: LerpRng,
+    alpha: f64,
+    centre: f64,
+}
+
+impl BlendCrossover {
+    pub fn new(seed: u64, alpha: f64) -> Self {
+        Self::from_state(LerpRng::new(seed).state(), alpha)
+    }
+
+    pub fn from_state(state: u64, alpha: f64) -> Self {
+        Self {
+            rng: LerpRng::from_state(state),
+            alpha,
+            centre: 0.5,
+        }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.rng.state()
+    }
+}
+
+impl IsLerpingMethod for BlendCrossover {
+    fn has_lerp_stepped(&self) -> bool {
+        self.lerp_pct() > 0.5
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.lerp_pct() * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.centre + (1.0 + 2.0 * self.alpha) * (self.rng.peek_f64() - 0.5)
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.centre = pct;
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork_str(name);
+        c
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork(i as u64);
+        c
+    }
+}
+
+// every numeric leaf is the same weighted mean of the parents, `weight` of the way to
+// `other`. other leaves, and the lengths of Vecs, take `other`'s with probability `weight`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArithmeticCrossover {
+    pub(crate) rng: LerpRng,
+    weight: f64,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(seed: u64, weight: f64) -> Self {
+        Self::from_state(LerpRng::new(seed).state(), weight)
+    }
+
+    pub fn from_state(state: u64, weight: f64) -> Self {
+        Self {
+            rng: LerpRng::from_state(state),
+            weight,
+        }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.rng.state()
+    }
+
+    // draws the weight from the seed too
+    pub fn random(seed: u64) -> Self {
+        let mut rng = LerpRng::new(seed);
+        let weight = rng.next_f64();
+        Self { rng, weight }
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+impl IsLerpingMethod for ArithmeticCrossover {
+    fn has_lerp_stepped(&self) -> bool {
+        self.rng.peek_f64() < self.weight
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.weight * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.weight
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.weight = pct;
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork_str(name);
+        c
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork(i as u64);
+        c
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions {
+            pick_lengths: true,
+            ..LerpOptions::default()
+        }
+    }
+}
+
+// wraps another method and adds gaussian noise with std dev `sigma` to each numeric leaf,
+// with probability `rate`. to mutate a single value, lerp it with itself:
+// `a.lerpify(&a, &GaussianMutation::new(0.0, seed, 0.1))`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaussianMutation<M: IsLerpingMethod> {
+    pub(crate) inner: M,
+    pub(crate) rng: LerpRng,
+    pub(crate) sigma: f64,
+    pub(crate) rate: f64,
+}
+
+impl<M: IsLerpingMethod> GaussianMutation<M> {
+    pub fn new(inner: M, seed: u64, sigma: f64) -> Self {
+        Self::from_state(inner, LerpRng::new(seed).state(), sigma)
+    }
+
+    pub fn from_state(inner: M, state: u64, sigma: f64) -> Self {
+        Self {
+            inner,
+            rng: LerpRng::from_state(state),
+            sigma,
+            rate: 1.0,
+        }
+    }
+
+    // only this one's, `inner` has its own
+    pub fn state(&self) -> u64 {
+        self.rng.state()
+    }
+
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for GaussianMutation<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.inner.has_lerp_stepped()
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.inner = self.inner.with_lerp_pct(pct);
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self {
+            inner: self.inner.enter_field(name),
+            rng: self.rng.fork_str(name),
+            sigma: self.sigma,
+            rate: self.rate,
+        }
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self {
+            inner: self.inner.enter_index(i),
+            rng: self.rng.fork(i as u64),
+            sigma: self.sigma,
+            rate: self.rate,
+        }
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        let options = self.inner.lerp_options();
+        let mut rng = self.rng;
+        if rng.next_f64() < self.rate {
+            LerpOptions {
+                noise: options.noise + self.sigma * rng.next_gaussian(),
+                ..options
+            }
+        } else {
+            options
+        }
+    }
+}
+
+// how `recombine` combines each leaf
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Recombination {
+    // each leaf comes from one parent, chosen with probability proportional to its weight
+    Pick,
+    // numbers are the weighted mean of the parents, other leaves are picked like `Pick`
+    WeightedMean,
+    // starts from the parent with the largest weight, and for each other parent in turn,
+    // moves each number a normally distributed fraction (std dev `sigma`) of the way to it.
+    // other leaves are picked like `Pick`.
+    ParentCentric { sigma: f64 },
+}
+
+// the leaf method for `Recombination::ParentCentric`. `bias` is the chance of taking the
+// other parent's value for leaves that aren't numbers, and for Vec lengths. numbers are
+// normally distributed around `centre`, which is 0 (the centre parent) unless it's moved
+// by `with_lerp_pct`, like `fade` does.
+#[derive(Clone, Debug, PartialEq)]
+struct ParentCentricStep {
+    rng: LerpRng,
+    sigma: f64,
+    bias: f64,
+    centre: f64,
+}
+
+impl IsLerpingMethod for ParentCentricStep {
+    fn has_lerp_stepped(&self) -> bool {
+        self.rng.peek_f64() < self.bias
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.lerp_pct() * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        let mut rng = self.rng;
+        self.centre + self.sigma * rng.next_gaussian()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.centre = pct;
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork_str(name);
+        c
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        let mut c = self.clone();
+        c.rng = self.rng.fork(i as u64);
+        c
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions {
+            pick_lengths: true,
+            ..LerpOptions::default()
+        }
+    }
+}
+
+// combines any number of parents into a child. this folds the parents in pairwise with
+// `lerpify`. each Vec's length is one of the parents' lengths, drawn in proportion to their
+// weights, and its elements are matched up the same way as `lerp_vecs`. panics if there are
+// no parents or the weights don't line up.
+pub fn recombine<T: Lerpable>(parents: &[T], weights: &[f64], how: Recombination, seed: u64) -> T {
+    assert!(!parents.is_empty(), "recombine needs at least one parent");
+    assert_eq!(
+        parents.len(),
+        weights.len(),
+        "recombine needs one weight per parent"
+    );
+
+    let mut rng = LerpRng::new(seed);
+
+    match how {
+        Recombination::Pick | Recombination::WeightedMean => {
+            // after folding in parent k, each leaf has come from parent j with probability
+            // w_j / (w_0 + .. + w_k), so by the end it's proportional to the weights
+            let mut child = parents[0].clone();
+            let mut total = weights[0];
+            for (parent, weight) in parents.iter().zip(weights).skip(1) {
+                total += weight;
+                if total <= 0.0 {
+                    continue;
+                }
+                let pct = weight / total;
+                let seed = rng.next_u64();
+                child = if how == Recombination::Pick {
+                    child.lerpify(parent, &UniformCrossover::new(seed).with_bias(pct))
+                } else {
+                    child.lerpify(parent, &ArithmeticCrossover::new(seed, pct))
+                };
+            }
+            child
+        }
+        Recombination::ParentCentric { sigma } => {
+            let centre =
+                weights
+                    .iter()
+                    .enumerate()
+                    .fold(0, |best, (i, w)| if *w > weights[best] { i } else { best });
+            // the same as `Pick` for which parent the other leaves come from
+            let mut child = parents[centre].clone();
+            let mut total = weights[centre];
+            for (i, (parent, weight)) in parents.iter().zip(weights).enumerate() {
+                if i == centre {
+                    continue;
+                }
+                total += weight;
+                let method = ParentCentricStep {
+                    rng: LerpRng::new(rng.next_u64()),
+                    sigma,
+                    bias: if total > 0.0 { weight / total } else { 0.0 },
+                    centre: 0.0,
+                };
+                child = child.lerpify(parent, &method);
+            }
+            child
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_crossover_vec_lengths_stay_between_parents() {
+        let a = vec![1.0, 2.0];
+        let b = vec![1.0, 2.0, 3.0, 4.0];
+        for seed in 0..64 {
+            let child = a.lerpify(&b, &BlendCrossover::new(seed, 0.5));
+            assert!((2..=4).contains(&child.len()), "seed {seed}: {child:?}");
+        }
+    }
+
+    #[test]
+    fn uniform_crossover_picks_each_leaf_from_a_parent() {
+        let a: Vec<f64> = (0..32).map(|x| x as f64).collect();
+        let b: Vec<f64> = (0..32).map(|x| -1.0 - x as f64).collect();
+        let child = a.lerpify(&b, &UniformCrossover::new(9));
+        for (i, x) in child.iter().enumerate() {
+            assert!(*x == a[i] || *x == b[i], "{i}: {x}");
+        }
+        // some of each
+        assert!(child.iter().any(|x| *x >= 0.0) && child.iter().any(|x| *x < 0.0));
+        // and the same every time for a seed
+        assert_eq!(child, a.lerpify(&b, &UniformCrossover::new(9)));
+        assert_ne!(child, a.lerpify(&b, &UniformCrossover::new(10)));
+
+        let words = vec!["a".to_owned(), "b".to_owned()];
+        let other = vec!["c".to_owned(), "d".to_owned()];
+        for w in words.lerpify(&other, &UniformCrossover::new(9)) {
+            assert!(words.contains(&w) || other.contains(&w));
+        }
+    }
+
+    #[test]
+    fn arithmetic_crossover_is_the_weighted_mean() {
+        let a = vec![0.0, 10.0, -4.0];
+        let b = vec![4.0, 20.0, 4.0];
+        for weight in [0.0, 0.25, 0.5, 1.0] {
+            let child = a.lerpify(&b, &ArithmeticCrossover::new(1, weight));
+            for i in 0..a.len() {
+                assert_eq!(child[i], a[i] + weight * (b[i] - a[i]));
+            }
+        }
+        assert_eq!(
+            3i32.lerpify(&7, &ArithmeticCrossover::new(1, 0.25)),
+            4,
+            "integers round"
+        );
+    }
+
+    #[test]
+    fn gaussian_mutation_at_rate_zero_does_nothing() {
+        let a = vec![1.5, -2.0, 1e6];
+        let off = GaussianMutation::new(0.0, 4, 10.0).with_rate(0.0);
+        assert_eq!(a.lerpify(&a, &off), a);
+        assert_eq!(7u8.lerpify(&7, &off), 7);
+        // and at rate 1, everything moves
+        let on = GaussianMutation::new(0.0, 4, 10.0);
+        let mutated = a.lerpify(&a, &on);
+        assert!(mutated.iter().zip(&a).all(|(x, y)| x != y), "{mutated:?}");
+    }
+
+    #[test]
+    fn methods_can_be_rebuilt_from_their_state() {
+        let (a, b) = (vec![1.0, 2.0, 3.0], vec![4.0, 5.0]);
+        let uniform = UniformCrossover::new(5).with_bias(0.3).enter_field("x");
+        let again = UniformCrossover::from_state(uniform.state()).with_bias(0.3);
+        assert_eq!(again, uniform);
+        assert_eq!(a.lerpify(&b, &again), a.lerpify(&b, &uniform));
+
+        let blend = BlendCrossover::new(6, 0.5).enter_index(2);
+        assert_eq!(BlendCrossover::from_state(blend.state(), 0.5), blend);
+
+        let arithmetic = ArithmeticCrossover::new(7, 0.25);
+        assert_eq!(
+            ArithmeticCrossover::from_state(arithmetic.state(), 0.25),
+            arithmetic
+        );
+
+        let mutation = GaussianMutation::new(0.5, 8, 0.1).with_rate(0.5);
+        let again = GaussianMutation::from_state(0.5, mutation.state(), 0.1).with_rate(0.5);
+        assert_eq!(again, mutation);
+    }
+
+    #[test]
+    fn with_lerp_pct_moves_the_draws() {
+        for seed in 0..64 {
+            let blend = BlendCrossover::new(seed, 0.25);
+            // the usual range, from -alpha to 1 + alpha
+            assert!((-0.25..=1.25).contains(&blend.lerp_pct()));
+            // the same draw, moved so it's centred on the new pct
+            let moved = blend.with_lerp_pct(0.1);
+            assert!((moved.lerp_pct() - (blend.lerp_pct() - 0.4)).abs() < 1e-12);
+            assert!((-0.65..=0.85).contains(&moved.lerp_pct()));
+
+            let step = ParentCentricStep {
+                rng: LerpRng::new(seed),
+                sigma: 0.1,
+                bias: 0.5,
+                centre: 0.0,
+            };
+            let moved = step.with_lerp_pct(0.75);
+            assert!((moved.lerp_pct() - (step.lerp_pct() + 0.75)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn recombined_lengths_come_from_the_parents() {
+        let parents = vec![vec![1.0, 2.0], vec![1.0, 2.0, 3.0, 4.0], vec![5.0; 4]];
+        for how in [
+            Recombination::Pick,
+            Recombination::WeightedMean,
+            Recombination::ParentCentric { sigma: 1.0 },
+        ] {
+            let mut lengths = vec![];
+            for seed in 0..64 {
+                let child = recombine(&parents, &[1.0, 1.0, 1.0], how, seed);
+                assert!([2, 4].contains(&child.len()), "{how:?} {seed}: {child:?}");
+                lengths.push(child.len());
+            }
+            // both come up
+            assert!(lengths.contains(&2) && lengths.contains(&4), "{how:?}");
+        }
+    }
+}
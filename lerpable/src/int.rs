@@ -0,0 +1,528 @@
+// exact integer lerping. the pct is an f64, which is exactly some integer times a power of
+// two, so `start + (end - start) * pct` can be worked out exactly and then rounded, instead
+// of going through f64 and losing everything past 2^53. the difference is kept as a sign and
+// a u128, so this works all the way up to u128 and i128.
+
+use crate::float::geometric_pct;
+use crate::{IsLerpingMethod, LerpError, LerpErrorReason, LerpOptions, Rounding, Spacing};
+
+// what to do when an integer lerp lands outside its type, which can happen when the pct is
+// outside 0 to 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    Saturate, // the default, stop at the type's MIN or MAX
+    Wrap,     // two's complement, like `wrapping_add`
+    Clamp,    // stay between start and end, even inside the type's range
+    Checked,  // `try_lerp_int` gives an error, the infallible `lerpify` saturates
+}
+
+// sets the overflow policy for integer leaves, and passes everything else through
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnOverflow<M: IsLerpingMethod> {
+    inner: M,
+    policy: Overflow,
+}
+
+impl<M: IsLerpingMethod> OnOverflow<M> {
+    pub fn new(inner: M, policy: Overflow) -> Self {
+        Self { inner, policy }
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for OnOverflow<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.inner.has_lerp_stepped()
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        Self::new(self.inner.with_lerp_pct(pct), self.policy)
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self::new(self.inner.enter_field(name), self.policy)
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self::new(self.inner.enter_index(i), self.policy)
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions {
+            overflow: self.policy,
+            ..self.inner.lerp_options()
+        }
+    }
+}
+
+// the integer types `lerp_int` works with. offsets are a sign and a magnitude, so they cover
+// the difference between any two values of the type.
+pub trait LerpInt: Copy + Ord {
+    const TYPE_NAME: &'static str;
+    const MIN: Self;
+    const MAX: Self;
+
+    // (whether `other` is less than `self`, the distance between them)
+    fn distance(self, other: Self) -> (bool, u128);
+
+    // close enough to place it in log space, see `Spacing::Geometric`
+    fn to_f64(self) -> f64;
+
+    // None if it's outside the type
+    fn checked_offset(self, negative: bool, magnitude: u128) -> Option<Self>;
+
+    fn wrapping_offset(self, negative: bool, magnitude: u128) -> Self;
+}
+
+macro_rules! impl_lerp_int_signed {
+    ($t:ty) => {
+        impl LerpInt for $t {
+            const TYPE_NAME: &'static str = stringify!($t);
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn distance(self, other: Self) -> (bool, u128) {
+                (other < self, (self as i128).abs_diff(other as i128))
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn checked_offset(self, negative: bool, magnitude: u128) -> Option<Self> {
+                let value = if negative {
+                    (self as i128).checked_sub_unsigned(magnitude)
+                } else {
+                    (self as i128).checked_add_unsigned(magnitude)
+                };
+                value.and_then(|x| <$t>::try_from(x).ok())
+            }
+
+            fn wrapping_offset(self, negative: bool, magnitude: u128) -> Self {
+                let value = if negative {
+                    (self as i128).wrapping_sub_unsigned(magnitude)
+                } else {
+                    (self as i128).wrapping_add_unsigned(magnitude)
+                };
+                value as $t
+            }
+        }
+    };
+}
+
+macro_rules! impl_lerp_int_unsigned {
+    ($t:ty) => {
+        impl LerpInt for $t {
+            const TYPE_NAME: &'static str = stringify!($t);
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn distance(self, other: Self) -> (bool, u128) {
+                (other < self, (self as u128).abs_diff(other as u128))
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn checked_offset(self, negative: bool, magnitude: u128) -> Option<Self> {
+                let value = if negative {
+                    (self as u128).checked_sub(magnitude)
+                } else {
+                    (self as u128).checked_add(magnitude)
+                };
+                value.and_then(|x| <$t>::try_from(x).ok())
+            }
+
+            fn wrapping_offset(self, negative: bool, magnitude: u128) -> Self {
+                let value = if negative {
+                    (self as u128).wrapping_sub(magnitude)
+                } else {
+                    (self as u128).wrapping_add(magnitude)
+                };
+                value as $t
+            }
+        }
+    };
+}
+
+impl_lerp_int_signed!(i8);
+impl_lerp_int_signed!(i16);
+impl_lerp_int_signed!(i32);
+impl_lerp_int_signed!(i64);
+impl_lerp_int_signed!(i128);
+impl_lerp_int_signed!(isize);
+impl_lerp_int_unsigned!(u8);
+impl_lerp_int_unsigned!(u16);
+impl_lerp_int_unsigned!(u32);
+impl_lerp_int_unsigned!(u64);
+impl_lerp_int_unsigned!(u128);
+impl_lerp_int_unsigned!(usize);
+
+// a signed integer too big for i128, as a sign and the low 128 bits of the magnitude
+#[derive(Clone, Copy, Debug)]
+struct Offset {
+    negative: bool,
+    magnitude: u128,
+    overflowed: bool, // the magnitude didn't fit in 128 bits
+}
+
+impl Offset {
+    fn add(self, n: i128) -> Self {
+        let (n_negative, n_magnitude) = (n < 0, n.unsigned_abs());
+        if n_negative == self.negative {
+            let (magnitude, carry) = self.magnitude.overflowing_add(n_magnitude);
+            Self {
+                negative: self.negative,
+                magnitude,
+                overflowed: self.overflowed || carry,
+            }
+        } else if self.overflowed || self.magnitude >= n_magnitude {
+            Self {
+                magnitude: self.magnitude.wrapping_sub(n_magnitude),
+                ..self
+            }
+        } else {
+            Self {
+                negative: n_negative,
+                magnitude: n_magnitude - self.magnitude,
+                overflowed: false,
+            }
+        }
+    }
+}
+
+// pct as mantissa * 2^exponent, both exact, ignoring the sign
+fn split_f64(pct: f64) -> (u64, i32) {
+    let bits = pct.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    if exponent == 0 {
+        // subnormal
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, exponent - 1075)
+    }
+}
+
+// distance * |pct|, as (the low 128 bits of the whole part, whether it overflowed, the
+// fractional part). the fractional part is only approximate, but it's never 0 or 1 when it
+// shouldn't be, so rounding with it is exact except when it's very close to a tie.
+fn scale(distance: u128, pct: f64) -> (u128, bool, f64) {
+    let (mantissa, exponent) = split_f64(pct);
+
+    // distance * mantissa as 256 bits, in hi and lo
+    let mantissa = mantissa as u128;
+    let p0 = (distance as u64 as u128) * mantissa;
+    let p1 = (distance >> 64) * mantissa;
+    let lo = p0.wrapping_add(p1 << 64);
+    let hi = (p1 >> 64) + (lo < p0) as u128;
+
+    let (whole, overflowed, frac) = if exponent >= 0 {
+        let e = exponent as u32;
+        let overflowed = hi != 0 || e >= 128 || (e > 0 && lo >> (128 - e) != 0);
+        (lo.checked_shl(e).unwrap_or(0), overflowed, 0.0)
+    } else {
+        let k = exponent.unsigned_abs();
+        let (whole, overflowed, rem_hi, rem_lo) = if k < 128 {
+            let mask = (1u128 << k) - 1;
+            ((hi << (128 - k)) | (lo >> k), hi >> k != 0, 0, lo & mask)
+        } else if k < 256 {
+            let mask = (1u128 << (k - 128)) - 1;
+            (hi >> (k - 128), false, hi & mask, lo)
+        } else {
+            (0, false, hi, lo)
+        };
+        // dividing by 2^k in two steps, so it doesn't underflow too early
+        let k = k as i32;
+        let frac = (rem_hi as f64 * 2f64.powi(128) + rem_lo as f64)
+            * 2f64.powi(-(k / 2))
+            * 2f64.powi(-(k - k / 2));
+        let frac = if rem_hi == 0 && rem_lo == 0 {
+            0.0
+        } else {
+            frac.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON / 2.0)
+        };
+        (whole, overflowed, frac)
+    };
+    (whole, overflowed, frac)
+}
+
+// the rounded offset from `start`, or None to keep `start` (a NaN pct or noise)
+fn lerp_offset<T: LerpInt, LerpMethod: IsLerpingMethod>(
+    start: T,
+    end: T,
+    pct: &LerpMethod,
+) -> Option<Offset> {
+    let options = pct.lerp_options();
+    let p = match options.spacing {
+        Spacing::Linear => pct.lerp_pct(),
+        // as far along as the float would be, so it still rounds and overflows the same way
+        Spacing::Geometric => geometric_pct(start.to_f64(), end.to_f64(), pct.lerp_pct()),
+    };
+    let noise = options.noise;
+    if p.is_nan() || noise.is_nan() {
+        return None;
+    }
+
+    let (diff_negative, distance) = start.distance(end);
+    let negative = diff_negative != (p < 0.0);
+    if distance == 0 || p == 0.0 {
+        let zero = Offset {
+            negative: false,
+            magnitude: 0,
+            overflowed: false,
+        };
+        return Some(zero.add(noise.round() as i128));
+    }
+    if p.is_infinite() {
+        return Some(Offset {
+            negative,
+            magnitude: u128::MAX,
+            overflowed: true,
+        });
+    }
+
+    // as floor + frac, with 0 <= frac < 1
+    let (whole, overflowed, frac) = scale(distance, p);
+    let (offset, frac) = if negative && frac > 0.0 {
+        let offset = Offset {
+            negative,
+            magnitude: whole,
+            overflowed,
+        };
+        (offset.add(-1), 1.0 - frac)
+    } else {
+        let offset = Offset {
+            negative,
+            magnitude: whole,
+            overflowed,
+        };
+        (offset, frac)
+    };
+
+    let (offset, frac) = if noise != 0.0 {
+        let shifted = frac + noise;
+        let whole = shifted.floor();
+        (offset.add(whole as i128), shifted - whole)
+    } else {
+        (offset, frac)
+    };
+
+    let round_up = match options.rounding {
+        Rounding::Nearest => frac >= 0.5,
+        Rounding::Floor => false,
+        Rounding::Ceil => frac > 0.0,
+        Rounding::Stochastic(draw) => frac > draw,
+    };
+
+    Some(offset.add(round_up as i128))
+}
+
+// lerps between two integers exactly, rounding, adding noise, and handling overflow as the
+// method's `lerp_options` say. a pct of 0 or 1 (with no
+// noise) always gives back `start` or `end`, and a NaN pct keeps `start`. with
+// `Overflow::Checked`, this saturates, use `try_lerp_int` to get the error.
+pub fn lerp_int<T: LerpInt, LerpMethod: IsLerpingMethod>(start: T, end: T, pct: &LerpMethod) -> T {
+    let policy = match pct.lerp_options().overflow {
+        Overflow::Checked => Overflow::Saturate,
+        policy => policy,
+    };
+    lerp_int_with(start, end, pct, policy).unwrap_or(start)
+}
+
+// same as `lerp_int`, but with `Overflow::Checked`, going outside the type is an error
+pub fn try_lerp_int<T: LerpInt, LerpMethod: IsLerpingMethod>(
+    start: T,
+    end: T,
+    pct: &LerpMethod,
+) -> Result<T, LerpError> {
+    lerp_int_with(start, end, pct, pct.lerp_options().overflow)
+}
+
+fn lerp_int_with<T: LerpInt, LerpMethod: IsLerpingMethod>(
+    start: T,
+    end: T,
+    pct: &LerpMethod,
+    policy: Overflow,
+) -> Result<T, LerpError> {
+    let Some(offset) = lerp_offset(start, end, pct) else {
+        return Ok(start);
+    };
+
+    let checked = if offset.overflowed {
+        None
+    } else {
+        start.checked_offset(offset.negative, offset.magnitude)
+    };
+    let saturated = checked.unwrap_or(if offset.negative { T::MIN } else { T::MAX });
+
+    match policy {
+        Overflow::Saturate => Ok(saturated),
+        Overflow::Wrap => Ok(start.wrapping_offset(offset.negative, offset.magnitude)),
+        Overflow::Clamp => Ok(saturated.clamp(start.min(end), start.max(end))),
+        Overflow::Checked => checked.ok_or_else(|| {
+            LerpError::new(LerpErrorReason::Overflow {
+                type_name: T::TYPE_NAME,
+            })
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometric, Round};
+
+    fn assert_exact_ends<T: LerpInt + std::fmt::Debug>(start: T, end: T) {
+        assert_eq!(lerp_int(start, end, &0.0), start);
+        assert_eq!(lerp_int(start, end, &1.0), end);
+        for round in [Round::floor, Round::ceil, Round::nearest] {
+            assert_eq!(lerp_int(start, end, &round(0.0)), start);
+            assert_eq!(lerp_int(start, end, &round(1.0)), end);
+        }
+    }
+
+    #[test]
+    fn endpoints_are_exact() {
+        assert_exact_ends(u128::MIN, u128::MAX);
+        assert_exact_ends(u128::MAX, 0);
+        assert_exact_ends(i128::MIN, i128::MAX);
+        assert_exact_ends(i128::MAX, i128::MIN);
+        assert_exact_ends(i64::MIN, i64::MAX);
+        assert_exact_ends(u64::MAX - 1, 3);
+        assert_exact_ends(i8::MIN, i8::MAX);
+        assert_exact_ends(200u8, 7);
+        // past f64's 2^53, where going through floats would lose it
+        let big = (1u64 << 60) + 1;
+        assert_eq!(lerp_int(0, big, &1.0), big);
+        assert_eq!(lerp_int(big, big + 2, &0.5), big + 1);
+    }
+
+    #[test]
+    fn rounding_modes() {
+        // 0 to 3 at 0.5 is 1.5
+        assert_eq!(lerp_int(0, 3, &0.5), 2);
+        assert_eq!(lerp_int(0, 3, &Round::nearest(0.5)), 2);
+        assert_eq!(lerp_int(0, 3, &Round::floor(0.5)), 1);
+        assert_eq!(lerp_int(0, 3, &Round::ceil(0.5)), 2);
+        // the same, going down, rounds by value, not by distance from start
+        assert_eq!(lerp_int(0, -3, &Round::nearest(0.5)), -1);
+        assert_eq!(lerp_int(0, -3, &Round::floor(0.5)), -2);
+        assert_eq!(lerp_int(0, -3, &Round::ceil(0.5)), -1);
+        assert_eq!(lerp_int(3u8, 0, &Round::floor(0.5)), 1);
+        assert_eq!(lerp_int(3u8, 0, &Round::ceil(0.5)), 2);
+        // exact values don't round either way
+        assert_eq!(lerp_int(0, 4, &Round::floor(0.5)), 2);
+        assert_eq!(lerp_int(0, 4, &Round::ceil(0.5)), 2);
+        // a NaN pct keeps the start
+        assert_eq!(lerp_int(5, 10, &f64::NAN), 5);
+    }
+
+    #[test]
+    fn stochastic_rounding_is_seeded_and_stays_between() {
+        let draws: Vec<i32> = (0..200)
+            .map(|seed| lerp_int(0, 3, &Round::stochastic(0.5, seed)))
+            .collect();
+        assert!(draws.iter().all(|x| *x == 1 || *x == 2));
+        // roughly half each way
+        let ups = draws.iter().filter(|x| **x == 2).count();
+        assert!((60..140).contains(&ups), "{ups}");
+        assert_eq!(
+            lerp_int(0, 3, &Round::stochastic(0.5, 7)),
+            lerp_int(0, 3, &Round::stochastic(0.5, 7))
+        );
+    }
+
+    #[test]
+    fn scale_is_exact_past_128_bits() {
+        assert_eq!(scale(10, 0.5), (5, false, 0.0));
+        assert_eq!(scale(3, 0.5), (1, false, 0.5));
+        assert_eq!(scale(5, 3.0), (15, false, 0.0));
+        assert_eq!(scale(u128::MAX, 1.0), (u128::MAX, false, 0.0));
+        assert_eq!(scale(u128::MAX, 0.5), (u128::MAX >> 1, false, 0.5));
+        // MAX = 4q + 3, so 3/4 of it is 3q + 2.25
+        assert_eq!(
+            scale(u128::MAX, 0.75),
+            ((u128::MAX >> 2) * 3 + 2, false, 0.25)
+        );
+        assert_eq!(scale(1 << 100, 2f64.powi(-100)), (1, false, 0.0));
+        // the product needs more than 128 bits before it's shifted back down
+        assert_eq!(scale(u128::MAX, 2f64.powi(-130)), (0, false, 0.25));
+        assert!(scale(u128::MAX, 2.0).1);
+        assert!(scale(1, 2f64.powi(128)).1);
+        assert!(!scale(1, 2f64.powi(127)).1);
+    }
+
+    #[test]
+    fn scale_never_rounds_a_fraction_away() {
+        // far too small to see in an f64 next to the whole part, but still not zero
+        let (whole, overflowed, frac) = scale(1, 2f64.powi(-200));
+        assert_eq!((whole, overflowed), (0, false));
+        assert!(frac > 0.0);
+        let (whole, _, frac) = scale(u128::MAX, f64::from_bits(1)); // smallest subnormal
+        assert_eq!(whole, 0);
+        assert!(frac > 0.0);
+        // and never 1, even when it's too close to 1 for an f64 to tell
+        let distance = (1 << 60) - 1;
+        let (whole, _, frac) = scale(distance, 2f64.powi(-60));
+        assert_eq!(whole, 0);
+        assert!(frac < 1.0);
+        assert_eq!(lerp_int(0, distance, &Round::floor(2f64.powi(-60))), 0);
+        assert_eq!(lerp_int(0, distance, &Round::ceil(2f64.powi(-60))), 1);
+    }
+
+    #[test]
+    fn full_range_lerps() {
+        // -0.5 and 2^127 - 0.5, ties round up
+        assert_eq!(lerp_int(i128::MIN, i128::MAX, &0.5), 0);
+        assert_eq!(lerp_int(0, u128::MAX, &0.5), 1 << 127);
+        assert_eq!(lerp_int(0, u128::MAX, &Round::floor(0.5)), (1 << 127) - 1);
+        assert_eq!(lerp_int(i128::MAX, i128::MIN, &Round::floor(0.5)), -1);
+
+        assert_eq!(lerp_int(0, u128::MAX, &2.0), u128::MAX);
+        assert_eq!(lerp_int(0, i128::MAX, &-1.0), -i128::MAX);
+        assert_eq!(lerp_int(0, i128::MAX, &-2.0), i128::MIN);
+        let wrap = crate::OnOverflow::new(2.0, Overflow::Wrap);
+        assert_eq!(lerp_int(0, u128::MAX, &wrap), u128::MAX - 1);
+        let checked = crate::OnOverflow::new(2.0, Overflow::Checked);
+        assert!(try_lerp_int(0, u128::MAX, &checked).is_err());
+        assert_eq!(
+            try_lerp_int(
+                0,
+                u128::MAX,
+                &crate::OnOverflow::new(1.0, Overflow::Checked)
+            ),
+            Ok(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn geometric_spacing() {
+        let log = |pct: f64| Geometric::new(pct);
+        assert_eq!(lerp_int(1u32, 100, &log(0.5)), 10);
+        assert_eq!(lerp_int(100u32, 1, &log(0.5)), 10);
+        assert_eq!(lerp_int(-1000i64, -10, &log(0.5)), -100);
+        assert_eq!(lerp_int(2u8, 200, &log(0.0)), 2);
+        assert_eq!(lerp_int(2u8, 200, &log(1.0)), 200);
+        // it's still rounded the usual ways, 1 to 64 at 0.25 is 2.83
+        assert_eq!(lerp_int(1u32, 64, &log(0.25)), 3);
+        assert_eq!(lerp_int(1u32, 64, &Round::floor(log(0.25))), 2);
+        // and overflows the same way, 1 to 255 at 1.25 is about 1012
+        let past = OnOverflow::new(log(1.25), Overflow::Checked);
+        assert!(try_lerp_int(1u8, 255, &past).is_err());
+        // a zero has no log space, so it's linear
+        assert_eq!(lerp_int(0i32, 100, &log(0.5)), 50);
+    }
+}
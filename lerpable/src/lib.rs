@@ -1,5 +1,45 @@
 pub use lerpable_derive::Lerpable;
 
+mod angle;
+pub mod color;
+mod error;
+mod fallible;
+mod field_path;
+mod float;
+mod genetic;
+mod int;
+mod plan;
+mod rng;
+pub mod rotation;
+mod round;
+mod stateful;
+mod step;
+pub mod transform;
+
+pub use angle::{lerp_angle, Angle, AngleDirection, AnglePath, Degrees, LerpAngle};
+pub use color::{Hsva, LinearRgba, Oklab, Oklch, Srgba};
+pub use error::{LerpError, LerpErrorReason};
+pub use fallible::{try_check, try_lerp_vecs, StrictLengths, TryLerpable};
+pub use field_path::{
+    FieldPath, FieldPathSegment, FieldPattern, PathOverride, PathOverrideTable, PathOverrides,
+};
+pub use float::{lerp_f32, lerp_f64, lerp_geometric, Geometric, Spacing};
+pub use genetic::{
+    recombine, ArithmeticCrossover, BlendCrossover, GaussianMutation, Recombination,
+    UniformCrossover,
+};
+pub use int::{lerp_int, try_lerp_int, LerpInt, OnOverflow, Overflow};
+pub use plan::{
+    FieldAttr, FieldLerpPlan, HowToControlThis, LerpHow, LerpPlan, LerpPlanKind, LerpReport,
+    LerpReportEntry, VariantChangePlan, VariantLerpPlan,
+};
+pub use rng::LerpRng;
+pub use rotation::{Quaternion, Rotation2, Rotor3};
+pub use round::{Round, Rounding, RoundingMode};
+pub use stateful::{IsLerpingMethodMut, LeafCounter};
+pub use step::{Keep, KeepSide, StepAt};
+pub use transform::{Mat3, Mat4, Transform2, Transform3};
+
 pub fn step<T: Clone, LerpMethod>(this: &T, other: &T, pct: &LerpMethod) -> T
 where
     LerpMethod: IsLerpingMethod,
@@ -11,6 +51,25 @@ where
     }
 }
 
+// for types that lerp as a whole, like colors, so that pct 0 and 1 give back exactly the
+// start and end instead of a round trip through another space
+pub(crate) fn with_exact_ends<T: Clone>(
+    this: &T,
+    other: &T,
+    pct: f64,
+    lerp: impl FnOnce() -> T,
+) -> T {
+    if pct == 0.0 {
+        this.clone()
+    } else if pct == 1.0 {
+        other.clone()
+    } else {
+        lerp()
+    }
+}
+
+// for any type that can be scaled. numbers should use `lerp_f64` or `lerp_int`, which are
+// exact at the ends.
 pub fn lerp<T, LerpMethod>(start: T, end: T, pct: &LerpMethod) -> T
 where
     T: std::ops::Mul<f64, Output = T> + std::ops::Add<Output = T>,
@@ -21,6 +80,43 @@ where
     (1.0 - pct) * start + pct * end
 }
 
+// the default `lerp_partial`, for `#[lerpable(with = "...")]` modules that don't need their
+// own: `pub use lerpable::pop_in as lerp_partial;`
+pub fn pop_in<T: Clone, LerpMethod: IsLerpingMethod>(this: &T, _pct: LerpMethod) -> T {
+    this.clone()
+}
+
+// for #[lerpable(clamp(...))] fields. NaN stays NaN.
+pub fn clamp<T: PartialOrd>(x: T, min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), _) if x < min => min,
+        (_, Some(max)) if x > max => max,
+        _ => x,
+    }
+}
+
+// how many elements to show between Vecs of these lengths. pcts outside 0 to 1 (like from
+// `BlendCrossover`) would go past both, so it stays between them. with `pick_lengths`, it's
+// one length or the other.
+pub(crate) fn lerp_count<LerpMethod: IsLerpingMethod>(
+    this_len: usize,
+    other_len: usize,
+    pct: &LerpMethod,
+) -> usize {
+    if this_len == other_len {
+        return this_len;
+    }
+    if pct.lerp_options().pick_lengths {
+        return if pct.has_lerp_stepped() {
+            other_len
+        } else {
+            this_len
+        };
+    }
+    let count = lerp_f64(this_len as f64, other_len as f64, pct.lerp_pct()).round() as usize;
+    count.clamp(this_len.min(other_len), this_len.max(other_len))
+}
+
 pub fn lerp_vecs<T, LerpMethod>(this: &[T], other: &[T], pct: &LerpMethod) -> Vec<T>
 where
     T: Clone + Lerpable,
@@ -32,11 +128,7 @@ where
     let other_len = other.len();
     // round is important! or can get cases where two things of the same length return a count of something less!
     // I'm paranoid so also doing a special check for that case..
-    let count = if this_len == other_len {
-        this_len
-    } else {
-        lerp(this_len as f64, other_len as f64, pct).round() as usize
-    };
+    let count = lerp_count(this_len, other_len, pct);
     for i in 0..count {
         let result = match (i >= this_len, i >= other_len) {
             (true, true) => unreachable!(),
@@ -48,7 +140,68 @@ where
                 let emerge_pct = pct.partial_lerp_pct(i, count);
                 this[i].lerp_partial(emerge_pct)
             }
-            (false, false) => this[i].lerpify(&other[i], pct),
+            (false, false) => this[i].lerpify(&other[i], &pct.enter_index(i)),
+        };
+        v.push(result);
+    }
+    v
+}
+
+// for #[lerpable(ordinal)] enums. lerps between the positions (discriminants) of the two
+// variants, and returns the index in `positions` of the variant closest to that, so it
+// passes through the ones in between. ties go to the one closer to the start.
+pub fn lerp_ordinal<LerpMethod>(
+    this: i128,
+    other: i128,
+    positions: &[i128],
+    pct: &LerpMethod,
+) -> usize
+where
+    LerpMethod: IsLerpingMethod,
+{
+    let target = lerp_f64(this as f64, other as f64, pct.lerp_pct());
+    let distance = |x: i128| (x as f64 - target).abs();
+    positions
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            distance(**a)
+                .total_cmp(&distance(**b))
+                .then((**a - this).abs().cmp(&(**b - this).abs()))
+        })
+        .map(|(i, _)| i)
+        .expect("ordinal enums need at least one variant")
+}
+
+// like lerp_vecs, but threads a stateful method through the elements in order
+pub fn lerp_vecs_mut<T, LerpMethod>(this: &[T], other: &[T], method: &mut LerpMethod) -> Vec<T>
+where
+    T: Clone + Lerpable,
+    LerpMethod: IsLerpingMethodMut,
+{
+    let this_len = this.len();
+    let other_len = other.len();
+    // only ask for a leaf if there's something to decide
+    let (count, pct) = if this_len == other_len {
+        (this_len, None)
+    } else {
+        let pct = method.next_leaf();
+        let count = lerp_count(this_len, other_len, &pct);
+        (count, Some(pct))
+    };
+    let mut v = vec![];
+    for i in 0..count {
+        let result = match (i >= this_len, i >= other_len, &pct) {
+            (true, true, _) => unreachable!(),
+            (true, false, Some(pct)) => other[i].lerp_partial(pct.partial_lerp_pct(i, count)),
+            (false, true, Some(pct)) => this[i].lerp_partial(pct.partial_lerp_pct(i, count)),
+            (false, false, _) => {
+                method.enter_index(i);
+                let result = this[i].lerpify_mut(&other[i], method);
+                method.exit();
+                result
+            }
+            (_, _, None) => unreachable!(), // same lengths, so we never run off the end
         };
         v.push(result);
     }
@@ -67,7 +220,60 @@ pub trait IsLerpingMethod: Clone {
 
     fn lerp_pct(&self) -> f64;
 
+    // the same pct, for f32s to lerp in single precision. methods that already have an f32
+    // can give it back directly.
+    fn lerp_pct_f32(&self) -> f32 {
+        self.lerp_pct() as f32
+    }
+
     fn with_lerp_pct(&self, pct: f64) -> Self; // when introducing a new method, this will always be called first
+
+    // called on the way down into a struct field, enum variant, or tuple field ("0"), so
+    // methods can do something different depending on where they are. see `PathOverrides`.
+    fn enter_field(&self, _name: &'static str) -> Self {
+        self.clone()
+    }
+
+    // same, but for the elements of a Vec
+    fn enter_index(&self, _i: usize) -> Self {
+        self.clone()
+    }
+
+    // how the leaves lerp, besides the pct. wrappers like `Round` change one option and
+    // pass the rest through from the method they wrap.
+    fn lerp_options(&self) -> LerpOptions {
+        LerpOptions::default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LerpOptions {
+    // added to numbers after they're lerped, e.g. for `GaussianMutation`
+    pub noise: f64,
+    // how integers round after they're lerped, see `Round`
+    pub rounding: Rounding,
+    // what integers do when they're lerped outside their type, see `OnOverflow`
+    pub overflow: Overflow,
+    // whether `try_lerpify` errors on Vecs of different lengths, see `StrictLengths`
+    pub strict_lengths: bool,
+    // how numbers are spaced between the ends, see `Geometric`
+    pub spacing: Spacing,
+    // whether Vecs of different lengths take one side's length, using `has_lerp_stepped`,
+    // instead of one in between. see `ArithmeticCrossover`.
+    pub pick_lengths: bool,
+}
+
+impl Default for LerpOptions {
+    fn default() -> Self {
+        Self {
+            noise: 0.0,
+            rounding: Rounding::Nearest,
+            overflow: Overflow::Saturate,
+            strict_lengths: false,
+            spacing: Spacing::Linear,
+            pick_lengths: false,
+        }
+    }
 }
 
 impl IsLerpingMethod for f64 {
@@ -98,6 +304,10 @@ impl IsLerpingMethod for f32 {
         *self as f64
     }
 
+    fn lerp_pct_f32(&self) -> f32 {
+        *self
+    }
+
     fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
         // (self - i / total) * total
         // or (self * total).fract()
@@ -112,31 +322,115 @@ impl IsLerpingMethod for f32 {
 pub trait Lerpable: Sized + Clone {
     fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self;
 
+    // same, but with a method that changes as it goes. the derive visits fields in order and
+    // calls `next_leaf` once for each leaf, so the default is to treat this as one leaf.
+    fn lerpify_mut<T: IsLerpingMethodMut>(&self, other: &Self, method: &mut T) -> Self {
+        self.lerpify(other, &method.next_leaf())
+    }
+
     // by default, they just pop into existance, but you can implement this to make it fade in.
     // `pct`` will be scaled using the method's `partial_pct`
     fn lerp_partial<T: IsLerpingMethod>(&self, _pct: T) -> Self {
         self.clone()
     }
+
+    // describes how this type will be lerped, for debugging. the derive fills this in.
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new(std::any::type_name::<Self>(), LerpPlanKind::Custom)
+    }
+
+    // adds an entry to `report` for each leaf, saying whether it differs and how it'll lerp.
+    // see `LerpReport::between`.
+    fn lerp_diff(&self, _other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        report.add(path.clone(), LerpHow::Custom, None)
+    }
 }
 
+// floats are lerped with `lerp_f64` or `lerp_f32`, in their own precision
 macro_rules! impl_lerpable {
+    ($t:ty, $lerp:ident, $lerp_pct:ident) => {
+        impl Lerpable for $t {
+            fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                let options = pct.lerp_options();
+                let value = match options.spacing {
+                    Spacing::Linear => $lerp(*self, *other, pct.$lerp_pct()),
+                    Spacing::Geometric => {
+                        lerp_geometric(*self as f64, *other as f64, pct.lerp_pct()) as $t
+                    }
+                };
+                let noise = options.noise;
+                // so there's no noise, -0.0 stays -0.0
+                if noise == 0.0 {
+                    value
+                } else {
+                    value + noise as $t
+                }
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+            }
+        }
+    };
+}
+
+// integers are lerped exactly with `lerp_int`
+macro_rules! impl_lerpable_int {
     ($t:ty) => {
         impl Lerpable for $t {
             fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
-                lerp(*self as f64, *other as f64, pct) as $t
+                lerp_int(*self, *other, pct)
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
             }
         }
     };
 }
 
-impl_lerpable!(usize);
-impl_lerpable!(u8);
-impl_lerpable!(u16);
-impl_lerpable!(u64);
-impl_lerpable!(i32);
-impl_lerpable!(i64);
-impl_lerpable!(f32);
-impl_lerpable!(f64);
+impl_lerpable_int!(usize);
+impl_lerpable_int!(u8);
+impl_lerpable_int!(u16);
+impl_lerpable_int!(u32);
+impl_lerpable_int!(u64);
+impl_lerpable_int!(u128);
+impl_lerpable_int!(isize);
+impl_lerpable_int!(i8);
+impl_lerpable_int!(i16);
+impl_lerpable_int!(i32);
+impl_lerpable_int!(i64);
+impl_lerpable_int!(i128);
+impl_lerpable!(f32, lerp_f32, lerp_pct_f32);
+impl_lerpable!(f64, lerp_f64, lerp_pct);
+
+// lerps the code point, skipping over the surrogates, which aren't chars
+impl Lerpable for char {
+    fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+        let code = lerp_int(*self as u32, *other as u32, pct).min(char::MAX as u32);
+        char::from_u32(code).unwrap_or(if code < 0xdc00 {
+            '\u{d7ff}'
+        } else {
+            '\u{e000}'
+        })
+    }
+
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new("char", LerpPlanKind::Lerp)
+    }
+
+    fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+    }
+}
 
 impl<T: Lerpable + Clone> Lerpable for Vec<T> {
     fn lerpify<LerpMethod: IsLerpingMethod>(&self, other: &Self, method: &LerpMethod) -> Self {
@@ -145,16 +439,114 @@ impl<T: Lerpable + Clone> Lerpable for Vec<T> {
         }
         lerp_vecs(self, other, method)
     }
+
+    fn lerpify_mut<LerpMethod: IsLerpingMethodMut>(
+        &self,
+        other: &Self,
+        method: &mut LerpMethod,
+    ) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return self.clone();
+        }
+        lerp_vecs_mut(self, other, method)
+    }
+
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new(
+            std::any::type_name::<Self>(),
+            LerpPlanKind::Vec(T::lerp_plan),
+        )
+    }
+
+    fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        if self.is_empty() || other.is_empty() {
+            report.add(
+                path.clone(),
+                LerpHow::Keep(KeepSide::Start),
+                Some(self.len() != other.len()),
+            );
+            return;
+        }
+        if self.len() != other.len() {
+            let how = LerpHow::Resize {
+                from: self.len(),
+                to: other.len(),
+            };
+            report.add(path.clone(), how, Some(true));
+        }
+        for (i, (this, other)) in self.iter().zip(other.iter()).enumerate() {
+            this.lerp_diff(other, &path.index(i), report);
+        }
+    }
+}
+
+// lerps what's inside if both are Some, otherwise steps
+impl<T: Lerpable> Lerpable for Option<T> {
+    fn lerpify<LerpMethod: IsLerpingMethod>(&self, other: &Self, method: &LerpMethod) -> Self {
+        match (self, other) {
+            (Some(this), Some(other)) => Some(this.lerpify(other, method)),
+            _ => step(self, other, method),
+        }
+    }
+
+    fn lerpify_mut<LerpMethod: IsLerpingMethodMut>(
+        &self,
+        other: &Self,
+        method: &mut LerpMethod,
+    ) -> Self {
+        match (self, other) {
+            (Some(this), Some(other)) => Some(this.lerpify_mut(other, method)),
+            _ => step(self, other, &method.next_leaf()),
+        }
+    }
+
+    fn lerp_partial<LerpMethod: IsLerpingMethod>(&self, pct: LerpMethod) -> Self {
+        self.as_ref().map(|x| x.lerp_partial(pct))
+    }
+
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new(
+            std::any::type_name::<Self>(),
+            LerpPlanKind::Option(T::lerp_plan),
+        )
+    }
+
+    fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        match (self, other) {
+            (Some(this), Some(other)) => this.lerp_diff(other, path, report),
+            _ => report.add(
+                path.clone(),
+                LerpHow::Step,
+                Some(self.is_some() != other.is_some()),
+            ),
+        }
+    }
 }
 
 impl Lerpable for bool {
     fn lerpify<LerpMethod: IsLerpingMethod>(&self, other: &Self, method: &LerpMethod) -> Self {
         step(self, other, method)
     }
+
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new("bool", LerpPlanKind::Step)
+    }
+
+    fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        report.add(path.clone(), LerpHow::Step, Some(self != other))
+    }
 }
 
 impl Lerpable for String {
     fn lerpify<LerpMethod: IsLerpingMethod>(&self, other: &Self, method: &LerpMethod) -> Self {
         step(self, other, method)
     }
+
+    fn lerp_plan() -> LerpPlan {
+        LerpPlan::new("String", LerpPlanKind::Step)
+    }
+
+    fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+        report.add(path.clone(), LerpHow::Step, Some(self != other))
+    }
 }
@@ -0,0 +1,366 @@
+use std::fmt;
+
+use crate::{FieldPath, KeepSide, Lerpable, Overflow, RoundingMode};
+
+// mirrors the derive's idea of what to do with a field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HowToControlThis {
+    Skip,        // step from the old object to the new one
+    LerpifyType, // apply its lerpify
+}
+
+// a description of how a type will be lerped. the derive fills this in from the attributes,
+// and the built-in impls describe themselves.
+#[derive(Clone, Debug)]
+pub struct LerpPlan {
+    pub type_name: &'static str,
+    pub kind: LerpPlanKind,
+}
+
+impl LerpPlan {
+    pub fn new(type_name: &'static str, kind: LerpPlanKind) -> Self {
+        Self { type_name, kind }
+    }
+
+    pub fn of<T: Lerpable>() -> Self {
+        T::lerp_plan()
+    }
+
+    fn fmt_indented(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        depth: usize,
+        seen: &mut Vec<&'static str>,
+    ) -> fmt::Result {
+        let recursive = seen.contains(&self.type_name);
+        match &self.kind {
+            LerpPlanKind::Lerp => writeln!(f, "{}: lerp", self.type_name),
+            LerpPlanKind::Step => writeln!(f, "{}: step", self.type_name),
+            LerpPlanKind::Custom => writeln!(f, "{}: custom", self.type_name),
+            _ if recursive => writeln!(f, "{}: (recursive)", self.type_name),
+            LerpPlanKind::Vec(inner) => {
+                writeln!(f, "{}: vec of", self.type_name)?;
+                seen.push(self.type_name);
+                write!(f, "{:width$}", "", width = (depth + 1) * 2)?;
+                inner().fmt_indented(f, depth + 1, seen)?;
+                seen.pop();
+                Ok(())
+            }
+            LerpPlanKind::Option(inner) => {
+                writeln!(
+                    f,
+                    "{}: option of, steps between Some and None",
+                    self.type_name
+                )?;
+                seen.push(self.type_name);
+                write!(f, "{:width$}", "", width = (depth + 1) * 2)?;
+                inner().fmt_indented(f, depth + 1, seen)?;
+                seen.pop();
+                Ok(())
+            }
+            LerpPlanKind::Via(inner) => {
+                writeln!(f, "{}: via", self.type_name)?;
+                seen.push(self.type_name);
+                write!(f, "{:width$}", "", width = (depth + 1) * 2)?;
+                inner().fmt_indented(f, depth + 1, seen)?;
+                seen.pop();
+                Ok(())
+            }
+            LerpPlanKind::Struct(fields) => {
+                writeln!(f, "{}: struct", self.type_name)?;
+                seen.push(self.type_name);
+                for field in fields {
+                    field.fmt_indented(f, depth + 1, seen)?;
+                }
+                seen.pop();
+                Ok(())
+            }
+            LerpPlanKind::Enum {
+                variants,
+                on_change,
+            } => {
+                writeln!(
+                    f,
+                    "{}: enum, {} between variants",
+                    self.type_name, on_change
+                )?;
+                seen.push(self.type_name);
+                for variant in variants {
+                    writeln!(f, "{:width$}{}", "", variant.name, width = (depth + 1) * 2)?;
+                    for field in &variant.fields {
+                        field.fmt_indented(f, depth + 2, seen)?;
+                    }
+                }
+                seen.pop();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for LerpPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0, &mut vec![])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum LerpPlanKind {
+    Lerp,   // numbers
+    Step,   // switches at the halfway point, like bool or String
+    Custom, // a hand-written Lerpable, so we can't see inside
+    Vec(fn() -> LerpPlan),
+    Option(fn() -> LerpPlan),   // lerps if both are Some
+    Via(fn() -> LerpPlan),      // #[lerpable(via = "...")], lerped as this other type
+    Struct(Vec<FieldLerpPlan>), // including newtypes, with a field named "0"
+    Enum {
+        variants: Vec<VariantLerpPlan>,
+        on_change: VariantChangePlan,
+    },
+}
+
+// what an enum does when the variants don't match
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariantChangePlan {
+    Step,                                 // the default
+    Fade, // #[lerpable(fade)], out through the old variant's lerp_partial, in through the new one's
+    Cross(&'static str), // #[lerpable(cross = "...")], hands both to a function
+    StepSharing(&'static [&'static str]), // steps, but keeps lerping these fields
+    Ordinal, // #[lerpable(ordinal)], steps through the variants in between
+}
+
+impl fmt::Display for VariantChangePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariantChangePlan::Step => write!(f, "step"),
+            VariantChangePlan::Fade => write!(f, "fade"),
+            VariantChangePlan::Cross(func) => write!(f, "cross {}", func),
+            VariantChangePlan::StepSharing(fields) => {
+                write!(f, "step (sharing {})", fields.join(", "))
+            }
+            VariantChangePlan::Ordinal => write!(f, "step through"),
+        }
+    }
+}
+
+// a field attribute that changes how the field lerps, for plans and reports
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldAttr {
+    Keep(KeepSide),
+    StepAt(f64),
+    Round(RoundingMode),
+    Overflow(Overflow),
+    Log,
+    Clamp {
+        min: Option<&'static str>, // the bounds as written
+        max: Option<&'static str>,
+    },
+}
+
+impl fmt::Display for FieldAttr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldAttr::Keep(KeepSide::Start) => write!(f, "keep = start"),
+            FieldAttr::Keep(KeepSide::End) => write!(f, "keep = end"),
+            FieldAttr::StepAt(at) => write!(f, "step_at = {}", at),
+            FieldAttr::Round(mode) => write!(f, "round = {}", format!("{:?}", mode).to_lowercase()),
+            FieldAttr::Overflow(policy) => {
+                write!(f, "overflow = {}", format!("{:?}", policy).to_lowercase())
+            }
+            FieldAttr::Log => write!(f, "log"),
+            FieldAttr::Clamp { min, max } => {
+                let bounds = [("min", min), ("max", max)]
+                    .iter()
+                    .filter_map(|(name, x)| x.map(|x| format!("{} = {}", name, x)))
+                    .collect::<Vec<_>>();
+                write!(f, "clamp({})", bounds.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldLerpPlan {
+    pub name: &'static str,
+    pub control: HowToControlThis,
+    pub method: Option<&'static str>,
+    pub func: Option<&'static str>,
+    pub attrs: Vec<FieldAttr>,
+    pub plan: Option<fn() -> LerpPlan>, // only when the field's own Lerpable is used
+}
+
+impl FieldLerpPlan {
+    fn fmt_indented(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        depth: usize,
+        seen: &mut Vec<&'static str>,
+    ) -> fmt::Result {
+        write!(f, "{:width$}{}", "", self.name, width = depth * 2)?;
+        if self.control == HowToControlThis::Skip {
+            return writeln!(f, " [skip]");
+        }
+        if let Some(method) = self.method {
+            write!(f, " [method = {}]", method)?;
+        }
+        for attr in &self.attrs {
+            write!(f, " [{}]", attr)?;
+        }
+        if let Some(func) = self.func {
+            return writeln!(f, " [func = {}]", func);
+        }
+        match self.plan {
+            Some(plan) => {
+                write!(f, " -> ")?;
+                plan().fmt_indented(f, depth, seen)
+            }
+            None => writeln!(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct VariantLerpPlan {
+    pub name: &'static str,
+    pub fields: Vec<FieldLerpPlan>,
+}
+
+// what will happen to one leaf in a `LerpReport`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LerpHow {
+    Lerp,
+    Step,
+    Custom,             // a hand-written Lerpable
+    Func(&'static str), // #[lerpable(func = "...")]
+    VariantChange {
+        from: &'static str,
+        to: &'static str,
+        how: VariantChangePlan,
+    },
+    Resize {
+        from: usize,
+        to: usize,
+    }, // a Vec changing length, the extra elements go through `lerp_partial`
+    Keep(KeepSide), // #[lerpable(keep = "...")], or lerping to or from an empty Vec
+}
+
+impl fmt::Display for LerpHow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LerpHow::Lerp => write!(f, "lerp"),
+            LerpHow::Step => write!(f, "step"),
+            LerpHow::Custom => write!(f, "custom"),
+            LerpHow::Func(func) => write!(f, "func {}", func),
+            LerpHow::VariantChange { from, to, how } => write!(f, "{} {} -> {}", how, from, to),
+            LerpHow::Resize { from, to } => write!(f, "resize {} -> {}", from, to),
+            LerpHow::Keep(KeepSide::Start) => write!(f, "keep start"),
+            LerpHow::Keep(KeepSide::End) => write!(f, "keep end"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LerpReportEntry {
+    pub path: FieldPath,
+    pub how: LerpHow,
+    pub method: Option<&'static str>, // set if a #[lerpable(method = "...")] is in charge
+    pub attrs: Vec<FieldAttr>,        // from the fields it's inside, innermost first
+    pub differs: Option<bool>,        // None if we can't tell
+}
+
+impl fmt::Display for LerpReportEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            write!(f, "(root)")?;
+        } else {
+            write!(f, "{}", self.path)?;
+        }
+        write!(f, ": {}", self.how)?;
+        if let Some(method) = self.method {
+            write!(f, " [method = {}]", method)?;
+        }
+        for attr in &self.attrs {
+            write!(f, " [{}]", attr)?;
+        }
+        match self.differs {
+            Some(true) => write!(f, " (differs)"),
+            Some(false) => write!(f, " (same)"),
+            None => write!(f, " (unknown)"),
+        }
+    }
+}
+
+// built up by `Lerpable::lerp_diff`, one entry per leaf
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LerpReport {
+    entries: Vec<LerpReportEntry>,
+}
+
+impl LerpReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn between<T: Lerpable>(a: &T, b: &T) -> Self {
+        let mut report = Self::new();
+        a.lerp_diff(b, &FieldPath::root(), &mut report);
+        report
+    }
+
+    pub fn add(&mut self, path: FieldPath, how: LerpHow, differs: Option<bool>) {
+        self.entries.push(LerpReportEntry {
+            path,
+            how,
+            method: None,
+            attrs: vec![],
+            differs,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // used by the derive to mark everything under a field with a custom method
+    pub fn set_method_since(&mut self, start: usize, method: &'static str) {
+        for entry in &mut self.entries[start..] {
+            entry.method.get_or_insert(method);
+        }
+    }
+
+    // used by the derive to mark everything under a field with its attributes. keep changes
+    // how they lerp, unless something further in already keeps a side.
+    pub fn add_attrs_since(&mut self, start: usize, attrs: &[FieldAttr]) {
+        for entry in &mut self.entries[start..] {
+            for attr in attrs {
+                match attr {
+                    FieldAttr::Keep(_) if matches!(entry.how, LerpHow::Keep(_)) => {}
+                    FieldAttr::Keep(side) => entry.how = LerpHow::Keep(*side),
+                    attr => entry.attrs.push(*attr),
+                }
+            }
+        }
+    }
+
+    pub fn entries(&self) -> &[LerpReportEntry] {
+        &self.entries
+    }
+
+    // everything that isn't known to be the same
+    pub fn differing(&self) -> impl Iterator<Item = &LerpReportEntry> {
+        self.entries.iter().filter(|x| x.differs != Some(false))
+    }
+}
+
+impl fmt::Display for LerpReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
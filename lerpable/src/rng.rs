@@ -0,0 +1,69 @@
+// a tiny splitmix64, so the random methods are reproducible and their state is just a u64
+// that can be saved and restored, without pulling in a dependency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LerpRng {
+    state: u64,
+}
+
+impl LerpRng {
+    pub fn new(seed: u64) -> Self {
+        // mix the seed so that nearby seeds don't start out correlated
+        let mut rng = Self { state: seed };
+        Self {
+            state: rng.next_u64(),
+        }
+    }
+
+    pub fn from_state(state: u64) -> Self {
+        Self { state }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    // standard normal, using Box-Muller
+    pub fn next_gaussian(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64(); // (0, 1], so the log is finite
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    // a new, independent stream. the methods fork on each field and index they enter, so
+    // every leaf gets its own numbers no matter what order things are visited in.
+    pub fn fork(&self, salt: u64) -> Self {
+        let mut rng = Self {
+            state: self.state ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        };
+        Self {
+            state: rng.next_u64(),
+        }
+    }
+
+    pub fn fork_str(&self, salt: &str) -> Self {
+        // fnv-1a
+        let hash = salt.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.fork(hash)
+    }
+
+    // the next f64 without advancing, for leaves that might ask more than once
+    pub(crate) fn peek_f64(&self) -> f64 {
+        let mut rng = *self;
+        rng.next_f64()
+    }
+}
@@ -0,0 +1,464 @@
+// rotations, which come out wrong when lerped component by component: quaternions stop being
+// unit length and can take the long way around. these lerp along the shortest arc, at a
+// constant angular velocity, so `start.angle_to(&start.lerpify(&end, &pct))` is
+// `pct * start.angle_to(&end)`.
+//
+//  - `Quaternion` and `Rotor3` (the geometric algebra version of the same thing) slerp.
+//    `#[lerpable(with = "lerpable::rotation::nlerp")]` on a `Quaternion` field normalizes a
+//    plain lerp instead, which is faster, but speeds up in the middle.
+//  - `Rotation2` is a 2D rotation as a unit complex number, and lerps its angle
+//
+// they're stored as f32s, but lerped in f64. inputs are normalized first, and a zero one is
+// treated as no rotation. pct 0 and 1 give back exactly the start and end.
+
+use crate::fallible::{impl_try_lerpable_finite, Finite};
+use crate::{
+    lerp_angle, with_exact_ends, AnglePath, FieldPath, IsLerpingMethod, LerpHow, LerpPlan,
+    LerpPlanKind, LerpReport, Lerpable,
+};
+
+type Vec4 = [f64; 4];
+
+fn dot(a: Vec4, b: Vec4) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn norm(a: Vec4) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn scale(a: Vec4, s: f64) -> Vec4 {
+    a.map(|x| x * s)
+}
+
+fn add(a: Vec4, b: Vec4) -> Vec4 {
+    std::array::from_fn(|i| a[i] + b[i])
+}
+
+// a zero or non-finite length gives the identity, which is first
+fn normalize(a: Vec4) -> Vec4 {
+    let n = norm(a);
+    if n > 0.0 && n.is_finite() {
+        scale(a, 1.0 / n)
+    } else {
+        [1.0, 0.0, 0.0, 0.0]
+    }
+}
+
+// q and -q are the same rotation, so flip `b` to be on the same side as `a`
+fn same_side(a: Vec4, b: Vec4) -> (Vec4, Vec4) {
+    let (a, b) = (normalize(a), normalize(b));
+    if dot(a, b) < 0.0 {
+        (a, scale(b, -1.0))
+    } else {
+        (a, b)
+    }
+}
+
+// the angle between them on the unit sphere, which is half the rotation between them. this
+// is more accurate than acos(dot) when they're close.
+fn arc(a: Vec4, b: Vec4) -> f64 {
+    let diff = add(a, scale(b, -1.0));
+    2.0 * norm(diff).atan2(norm(add(a, b)))
+}
+
+fn nlerp4(a: Vec4, b: Vec4, t: f64) -> Vec4 {
+    let (a, b) = same_side(a, b);
+    normalize(add(scale(a, 1.0 - t), scale(b, t)))
+}
+
+fn slerp4(a: Vec4, b: Vec4, t: f64) -> Vec4 {
+    let (a, b) = same_side(a, b);
+    let theta = arc(a, b);
+    if theta < 1e-9 {
+        // too close for sin(theta) to divide by, and a plain lerp is just as good
+        return normalize(add(scale(a, 1.0 - t), scale(b, t)));
+    }
+    let s = theta.sin();
+    let (wa, wb) = (((1.0 - t) * theta).sin() / s, (t * theta).sin() / s);
+    normalize(add(scale(a, wa), scale(b, wb)))
+}
+
+fn to_f32(a: Vec4) -> [f32; 4] {
+    a.map(|x| x as f32)
+}
+
+fn to_f64(a: [f32; 4]) -> Vec4 {
+    a.map(|x| x as f64)
+}
+
+// w + xi + yj + zk
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+// s + xy e12 + yz e23 + zx e31
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotor3 {
+    pub s: f32,
+    pub xy: f32,
+    pub yz: f32,
+    pub zx: f32,
+}
+
+// cos + sin i
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotation2 {
+    pub cos: f32,
+    pub sin: f32,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Rotor3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Rotation2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    pub fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Self { w, x, y, z }
+    }
+
+    // `angle` radians counterclockwise around `axis`, which doesn't need to be unit length
+    pub fn from_axis_angle(axis: [f32; 3], angle: f32) -> Self {
+        let [x, y, z] = axis.map(|x| x as f64);
+        let len = (x * x + y * y + z * z).sqrt();
+        if len == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle as f64 / 2.0).sin_cos();
+        let s = sin / len;
+        Self::from_array(to_f32([cos, x * s, y * s, z * s]))
+    }
+
+    fn to_array(self) -> [f32; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
+    fn from_array([w, x, y, z]: [f32; 4]) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn normalized(&self) -> Self {
+        Self::from_array(to_f32(normalize(to_f64(self.to_array()))))
+    }
+
+    // how far it'd need to rotate to get to `other`, in radians from 0 to PI
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        let (a, b) = same_side(to_f64(self.to_array()), to_f64(other.to_array()));
+        (2.0 * arc(a, b)) as f32
+    }
+
+    // rotates a point
+    pub fn rotate(&self, v: [f32; 3]) -> [f32; 3] {
+        let [w, x, y, z] = normalize(to_f64(self.to_array()));
+        let [vx, vy, vz] = v.map(|x| x as f64);
+        // v + 2w(q x v) + 2 q x (q x v)
+        let (tx, ty, tz) = (
+            2.0 * (y * vz - z * vy),
+            2.0 * (z * vx - x * vz),
+            2.0 * (x * vy - y * vx),
+        );
+        [
+            vx + w * tx + (y * tz - z * ty),
+            vy + w * ty + (z * tx - x * tz),
+            vz + w * tz + (x * ty - y * tx),
+        ]
+        .map(|x| x as f32)
+    }
+}
+
+impl Rotor3 {
+    pub const IDENTITY: Self = Self {
+        s: 1.0,
+        xy: 0.0,
+        yz: 0.0,
+        zx: 0.0,
+    };
+
+    pub fn new(s: f32, xy: f32, yz: f32, zx: f32) -> Self {
+        Self { s, xy, yz, zx }
+    }
+
+    // `angle` radians in the plane, from the first axis towards the second. `plane` is
+    // [xy, yz, zx], and doesn't need to be unit length.
+    pub fn from_angle_plane(angle: f32, plane: [f32; 3]) -> Self {
+        let [xy, yz, zx] = plane.map(|x| x as f64);
+        let len = (xy * xy + yz * yz + zx * zx).sqrt();
+        if len == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle as f64 / 2.0).sin_cos();
+        // e.g. e1 (cos + sin e12) = e2 when the angle is a quarter turn, so the bivector
+        // part goes negative for the sandwich product
+        let s = -sin / len;
+        Self::from_array(to_f32([cos, xy * s, yz * s, zx * s]))
+    }
+
+    fn to_array(self) -> [f32; 4] {
+        [self.s, self.xy, self.yz, self.zx]
+    }
+
+    fn from_array([s, xy, yz, zx]: [f32; 4]) -> Self {
+        Self { s, xy, yz, zx }
+    }
+
+    pub fn normalized(&self) -> Self {
+        Self::from_array(to_f32(normalize(to_f64(self.to_array()))))
+    }
+
+    // how far it'd need to rotate to get to `other`, in radians from 0 to PI
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        let (a, b) = same_side(to_f64(self.to_array()), to_f64(other.to_array()));
+        (2.0 * arc(a, b)) as f32
+    }
+}
+
+impl Rotation2 {
+    pub const IDENTITY: Self = Self { cos: 1.0, sin: 0.0 };
+
+    // counterclockwise, in radians
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = (angle as f64).sin_cos();
+        Self {
+            cos: cos as f32,
+            sin: sin as f32,
+        }
+    }
+
+    // in radians, from -PI to PI
+    pub fn angle(&self) -> f32 {
+        self.angle_f64() as f32
+    }
+
+    fn angle_f64(&self) -> f64 {
+        (self.sin as f64).atan2(self.cos as f64)
+    }
+
+    // normalized, in f64
+    pub(crate) fn cos_sin(&self) -> [f64; 2] {
+        let (sin, cos) = self.angle_f64().sin_cos();
+        [cos, sin]
+    }
+
+    // how far it'd need to rotate to get to `other`, in radians from 0 to PI
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        let (a, b) = (self.angle_f64(), other.angle_f64());
+        AnglePath::radians().delta(a, b).abs() as f32
+    }
+
+    pub fn rotate(&self, v: [f32; 2]) -> [f32; 2] {
+        let (sin, cos) = self.angle_f64().sin_cos();
+        let [x, y] = v.map(|x| x as f64);
+        [cos * x - sin * y, sin * x + cos * y].map(|x| x as f32)
+    }
+}
+
+impl Quaternion {
+    fn lerp_inner(&self, other: &Self, pct: f64) -> Self {
+        let q = slerp4(to_f64(self.to_array()), to_f64(other.to_array()), pct);
+        Self::from_array(to_f32(q))
+    }
+}
+
+impl Rotor3 {
+    fn lerp_inner(&self, other: &Self, pct: f64) -> Self {
+        let r = slerp4(to_f64(self.to_array()), to_f64(other.to_array()), pct);
+        Self::from_array(to_f32(r))
+    }
+}
+
+impl Rotation2 {
+    fn lerp_inner(&self, other: &Self, pct: f64) -> Self {
+        let (a, b) = (self.angle_f64(), other.angle_f64());
+        let (sin, cos) = lerp_angle(&a, &b, &pct, AnglePath::radians()).sin_cos();
+        Self {
+            cos: cos as f32,
+            sin: sin as f32,
+        }
+    }
+}
+
+macro_rules! impl_lerpable_rotation {
+    ($t:ident, $($component:ident),*) => {
+        impl Lerpable for $t {
+            fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                let pct = pct.lerp_pct();
+                with_exact_ends(self, other, pct, || self.lerp_inner(other, pct))
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+            }
+        }
+
+        impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                [$(self.$component),*].iter().all(|x| x.is_finite())
+            }
+        }
+
+        impl_try_lerpable_finite!($t);
+    };
+}
+
+impl_lerpable_rotation!(Quaternion, w, x, y, z);
+impl_lerpable_rotation!(Rotor3, s, xy, yz, zx);
+impl_lerpable_rotation!(Rotation2, cos, sin);
+
+// for `#[lerpable(with = "lerpable::rotation::nlerp")]` on `Quaternion` fields
+pub mod nlerp {
+    use super::*;
+
+    pub fn lerpify<T: IsLerpingMethod>(
+        this: &Quaternion,
+        other: &Quaternion,
+        pct: &T,
+    ) -> Quaternion {
+        let pct = pct.lerp_pct();
+        with_exact_ends(this, other, pct, || {
+            let q = nlerp4(to_f64(this.to_array()), to_f64(other.to_array()), pct);
+            Quaternion::from_array(to_f32(q))
+        })
+    }
+
+    pub fn lerp_partial<T: IsLerpingMethod>(this: &Quaternion, _pct: T) -> Quaternion {
+        *this
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    #[test]
+    fn slerp_turns_at_a_constant_speed() {
+        let pairs = [
+            (
+                Quaternion::IDENTITY,
+                Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0),
+            ),
+            (
+                Quaternion::from_axis_angle([1.0, 2.0, 3.0], 0.5),
+                Quaternion::from_axis_angle([-1.0, 0.5, 0.0], 2.5),
+            ),
+            // nearly the same
+            (
+                Quaternion::from_axis_angle([0.0, 1.0, 0.0], 1.0),
+                Quaternion::from_axis_angle([0.0, 1.0, 0.0], 1.001),
+            ),
+        ];
+        for (start, end) in pairs {
+            let total = start.angle_to(&end);
+            for i in 0..=20 {
+                let pct = i as f64 / 20.0;
+                let q = start.lerpify(&end, &pct);
+                let turned = start.angle_to(&q);
+                assert!(
+                    close(turned, pct as f32 * total),
+                    "{pct}: {turned} of {total}"
+                );
+                assert!(close(q.angle_to(&end), (1.0 - pct as f32) * total));
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_takes_the_shortest_way() {
+        let start = Quaternion::IDENTITY;
+        let end = Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0);
+        let flipped = Quaternion::new(-end.w, -end.x, -end.y, -end.z);
+        for pct in [0.25, 0.5, 0.75] {
+            let a = start.lerpify(&end, &pct);
+            let b = start.lerpify(&flipped, &pct);
+            assert!(close(a.angle_to(&b), 0.0), "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn rotor_and_rotation2_turn_at_a_constant_speed() {
+        let start = Rotor3::IDENTITY;
+        let end = Rotor3::from_angle_plane(2.0, [1.0, 1.0, 0.0]);
+        for pct in [0.25f64, 0.5, 0.75] {
+            let turned = start.angle_to(&start.lerpify(&end, &pct));
+            assert!(close(turned, 2.0 * pct as f32), "{turned}");
+        }
+
+        // crosses PI, so the short way is 2PI - 6
+        let start = Rotation2::from_angle(3.0);
+        let end = Rotation2::from_angle(-3.0);
+        let total = start.angle_to(&end);
+        assert!(close(total, std::f32::consts::TAU - 6.0));
+        for pct in [0.25f64, 0.5, 0.75] {
+            let turned = start.angle_to(&start.lerpify(&end, &pct));
+            assert!(close(turned, pct as f32 * total), "{turned}");
+        }
+    }
+
+    #[test]
+    fn endpoints_are_exact() {
+        let start = Quaternion::from_axis_angle([1.0, 0.0, 0.0], 0.3);
+        let end = Quaternion::new(0.0, 0.0, 0.0, -2.0); // not unit, and on the far side
+        assert_eq!(start.lerpify(&end, &0.0), start);
+        assert_eq!(start.lerpify(&end, &1.0), end);
+        assert_eq!(nlerp::lerpify(&start, &end, &0.0), start);
+        assert_eq!(nlerp::lerpify(&start, &end, &1.0), end);
+
+        let start = Rotation2::from_angle(1.0);
+        let end = Rotation2::from_angle(-2.0);
+        assert_eq!(start.lerpify(&end, &0.0), start);
+        assert_eq!(start.lerpify(&end, &1.0), end);
+    }
+
+    #[test]
+    fn nlerp_ends_up_in_the_same_place() {
+        let start = Quaternion::IDENTITY;
+        let end = Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0);
+        // near the ends it's the same rotation as slerp, and halfway it's the same by symmetry
+        for pct in [1e-9, 0.5, 1.0 - 1e-9] {
+            let n = nlerp::lerpify(&start, &end, &pct);
+            let s = start.lerpify(&end, &pct);
+            assert!(close(n.angle_to(&s), 0.0), "{pct}: {n:?} {s:?}");
+            assert!(close(n.normalized().w, n.w));
+        }
+    }
+
+    #[test]
+    fn zero_counts_as_no_rotation() {
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        let end = Quaternion::from_axis_angle([0.0, 1.0, 0.0], 1.0);
+        let q = zero.lerpify(&end, &0.5);
+        assert!(close(Quaternion::IDENTITY.angle_to(&q), 0.5));
+    }
+}
@@ -0,0 +1,112 @@
+// how integers round after they're lerped exactly, see `lerp_int`
+
+use crate::{IsLerpingMethod, LerpOptions, LerpRng};
+
+// how an integer leaf rounds the exact lerped value, see `LerpOptions::rounding`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    Nearest, // ties round up
+    Floor,
+    Ceil,
+    Stochastic(f64), // rounds up if the fractional part is more than this uniform draw in 0..1
+}
+
+// how `Round` picks a `Rounding`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    Nearest,
+    Floor,
+    Ceil,
+    Stochastic, // with a draw from the seeded rng, forked on every field and index
+}
+
+// sets how integer leaves round, and passes everything else through to `inner`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round<M: IsLerpingMethod> {
+    inner: M,
+    mode: RoundingMode,
+    rng: LerpRng,
+}
+
+impl<M: IsLerpingMethod> Round<M> {
+    pub fn new(inner: M, mode: RoundingMode) -> Self {
+        Self {
+            inner,
+            mode,
+            rng: LerpRng::new(0),
+        }
+    }
+
+    pub fn nearest(inner: M) -> Self {
+        Self::new(inner, RoundingMode::Nearest)
+    }
+
+    pub fn floor(inner: M) -> Self {
+        Self::new(inner, RoundingMode::Floor)
+    }
+
+    pub fn ceil(inner: M) -> Self {
+        Self::new(inner, RoundingMode::Ceil)
+    }
+
+    pub fn stochastic(inner: M, seed: u64) -> Self {
+        Self {
+            inner,
+            mode: RoundingMode::Stochastic,
+            rng: LerpRng::new(seed),
+        }
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for Round<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.inner.has_lerp_stepped()
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        let mut c = self.clone();
+        c.inner = self.inner.with_lerp_pct(pct);
+        c
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self {
+            inner: self.inner.enter_field(name),
+            mode: self.mode,
+            rng: self.rng.fork_str(name),
+        }
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self {
+            inner: self.inner.enter_index(i),
+            mode: self.mode,
+            rng: self.rng.fork(i as u64),
+        }
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        let rounding = match self.mode {
+            RoundingMode::Nearest => Rounding::Nearest,
+            RoundingMode::Floor => Rounding::Floor,
+            RoundingMode::Ceil => Rounding::Ceil,
+            RoundingMode::Stochastic => Rounding::Stochastic(self.rng.peek_f64()),
+        };
+        LerpOptions {
+            rounding,
+            ..self.inner.lerp_options()
+        }
+    }
+}
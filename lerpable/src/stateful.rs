@@ -0,0 +1,112 @@
+use crate::{
+    ArithmeticCrossover, BlendCrossover, GaussianMutation, IsLerpingMethod, UniformCrossover,
+};
+
+// a method that's threaded through `Lerpable::lerpify_mut` by `&mut`, so it can consume
+// randomness or count things as it goes. the derive visits fields in the order they're
+// declared, calling `enter_field`/`enter_index` on the way down and `exit` on the way back
+// up, and `next_leaf` whenever it needs a plain method to lerp something with.
+pub trait IsLerpingMethodMut {
+    type Leaf: IsLerpingMethod;
+
+    fn next_leaf(&mut self) -> Self::Leaf;
+
+    fn enter_field(&mut self, _name: &'static str) {}
+
+    fn enter_index(&mut self, _i: usize) {}
+
+    fn exit(&mut self) {}
+}
+
+// counts how many leaves were lerped, and lerps them all with `inner`
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeafCounter<M: IsLerpingMethod> {
+    inner: M,
+    count: usize,
+}
+
+impl<M: IsLerpingMethod> LeafCounter<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethodMut for LeafCounter<M> {
+    type Leaf = M;
+
+    fn next_leaf(&mut self) -> M {
+        self.count += 1;
+        self.inner.clone()
+    }
+}
+
+// the random methods can also be used statefully, in which case they draw from one stream
+// in field order instead of forking on every field.
+macro_rules! impl_sequential {
+    ($t:ty) => {
+        impl IsLerpingMethodMut for $t {
+            type Leaf = $t;
+
+            fn next_leaf(&mut self) -> $t {
+                let leaf = self.clone();
+                self.rng.next_u64();
+                leaf
+            }
+        }
+    };
+}
+
+impl_sequential!(UniformCrossover);
+impl_sequential!(BlendCrossover);
+impl_sequential!(ArithmeticCrossover);
+
+impl<M> IsLerpingMethodMut for GaussianMutation<M>
+where
+    M: IsLerpingMethod + IsLerpingMethodMut,
+{
+    type Leaf = GaussianMutation<M::Leaf>;
+
+    fn next_leaf(&mut self) -> GaussianMutation<M::Leaf> {
+        let leaf = GaussianMutation {
+            inner: self.inner.next_leaf(),
+            rng: self.rng,
+            sigma: self.sigma,
+            rate: self.rate,
+        };
+        self.rng.next_u64();
+        leaf
+    }
+
+    fn enter_field(&mut self, name: &'static str) {
+        IsLerpingMethodMut::enter_field(&mut self.inner, name)
+    }
+
+    fn enter_index(&mut self, i: usize) {
+        IsLerpingMethodMut::enter_index(&mut self.inner, i)
+    }
+
+    fn exit(&mut self) {
+        self.inner.exit()
+    }
+}
+
+// a plain pct doesn't change, but this means it can drive `lerpify_mut` too
+impl IsLerpingMethodMut for f64 {
+    type Leaf = f64;
+
+    fn next_leaf(&mut self) -> f64 {
+        *self
+    }
+}
+
+impl IsLerpingMethodMut for f32 {
+    type Leaf = f32;
+
+    fn next_leaf(&mut self) -> f32 {
+        *self
+    }
+}
@@ -0,0 +1,151 @@
+// method wrappers that change when non-numbers (and skip fields) switch from the start value
+// to the end value. these are what `#[lerpable(step_at = ...)]` and `#[lerpable(keep = ...)]`
+// wrap the field's method in.
+
+use crate::{IsLerpingMethod, LerpOptions};
+
+// numbers lerp as usual, but things that step switch once the pct passes `at` instead of
+// 0.5. at the end of the transition they've always switched, so `StepAt::new(pct, 1.0)`
+// switches right at the end and `StepAt::new(pct, 0.0)` right at the start.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepAt<M: IsLerpingMethod> {
+    inner: M,
+    at: f64,
+}
+
+impl<M: IsLerpingMethod> StepAt<M> {
+    pub fn new(inner: M, at: f64) -> Self {
+        Self { inner, at }
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for StepAt<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        let pct = self.inner.lerp_pct();
+        pct > self.at || pct >= 1.0
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.inner.partial_lerp_pct(i, total)
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        self.inner.lerp_pct()
+    }
+
+    fn lerp_pct_f32(&self) -> f32 {
+        self.inner.lerp_pct_f32()
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        Self::new(self.inner.with_lerp_pct(pct), self.at)
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self::new(self.inner.enter_field(name), self.at)
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self::new(self.inner.enter_index(i), self.at)
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        self.inner.lerp_options()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeepSide {
+    Start,
+    End,
+}
+
+// ignores the pct and always takes one side, numbers included
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keep<M: IsLerpingMethod> {
+    inner: M,
+    side: KeepSide,
+}
+
+impl<M: IsLerpingMethod> Keep<M> {
+    pub fn new(inner: M, side: KeepSide) -> Self {
+        Self { inner, side }
+    }
+
+    pub fn start(inner: M) -> Self {
+        Self::new(inner, KeepSide::Start)
+    }
+
+    pub fn end(inner: M) -> Self {
+        Self::new(inner, KeepSide::End)
+    }
+}
+
+impl<M: IsLerpingMethod> IsLerpingMethod for Keep<M> {
+    fn has_lerp_stepped(&self) -> bool {
+        self.side == KeepSide::End
+    }
+
+    fn partial_lerp_pct(&self, i: usize, total: usize) -> f64 {
+        self.lerp_pct() * total as f64 - i as f64
+    }
+
+    fn lerp_pct(&self) -> f64 {
+        match self.side {
+            KeepSide::Start => 0.0,
+            KeepSide::End => 1.0,
+        }
+    }
+
+    fn with_lerp_pct(&self, pct: f64) -> Self {
+        Self::new(self.inner.with_lerp_pct(pct), self.side)
+    }
+
+    fn enter_field(&self, name: &'static str) -> Self {
+        Self::new(self.inner.enter_field(name), self.side)
+    }
+
+    fn enter_index(&self, i: usize) -> Self {
+        Self::new(self.inner.enter_index(i), self.side)
+    }
+
+    fn lerp_options(&self) -> LerpOptions {
+        self.inner.lerp_options()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        GaussianMutation, Geometric, Lerpable, OnOverflow, Overflow, Round, Rounding, Spacing,
+        StrictLengths,
+    };
+
+    #[test]
+    fn wrappers_pass_the_other_options_through() {
+        let inner = StrictLengths::new(Geometric::new(OnOverflow::new(
+            Round::floor(0.5),
+            Overflow::Wrap,
+        )));
+        let expected = LerpOptions {
+            noise: 0.0,
+            rounding: Rounding::Floor,
+            overflow: Overflow::Wrap,
+            strict_lengths: true,
+            spacing: Spacing::Geometric,
+            pick_lengths: false,
+        };
+        assert_eq!(inner.lerp_options(), expected);
+        assert_eq!(StepAt::new(inner.clone(), 0.25).lerp_options(), expected);
+        assert_eq!(Keep::start(inner.clone()).lerp_options(), expected);
+        assert_eq!(Keep::end(inner).lerp_options(), expected);
+    }
+
+    #[test]
+    fn keep_still_mutates() {
+        let mutate = Keep::start(GaussianMutation::new(0.5, 7, 1.0));
+        assert_ne!(2.0f64.lerpify(&2.0, &mutate), 2.0);
+        assert_ne!(2i64.lerpify(&2, &mutate), 2);
+    }
+}
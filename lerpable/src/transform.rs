@@ -0,0 +1,623 @@
+// affine transforms. lerping a matrix element by element makes things shrink and shear
+// halfway through a rotation, so these split it into translation, rotation, scale, and shear,
+// lerp those, and put it back together.
+//
+//  - `Transform2` and `Transform3` hold the pieces. translation, scale, and shear lerp
+//    linearly, and the rotation slerps, see `rotation`. shear is zero unless you set it.
+//  - `Mat3` (2D, homogeneous) and `Mat4` (3D, homogeneous) are column-major matrices that
+//    lerp by going through those
+//
+// the matrix is split as translation * rotation * (scale + shear), where scale is diagonal
+// and shear is above it, so x moves `shear` for each unit of y. that always works, and always
+// the same way:
+//  - a reflection (negative determinant) is a negative scale on the last axis, y or z, so
+//    lerping from a reflection passes through flat instead of flipping
+//  - an axis squashed to nothing gets scale 0, and the rotation takes its direction from the
+//    next column that isn't squashed, or else the first of x, y, z that's furthest from the
+//    axes before it
+//  - a projective bottom row (not 0, ..., 0, 1) is lerped element by element
+//
+// pct 0 and 1 give back exactly the start and end.
+
+use crate::fallible::{impl_try_lerpable_finite, Finite};
+use crate::{
+    lerp_f64, with_exact_ends, FieldPath, IsLerpingMethod, LerpHow, LerpPlan, LerpPlanKind,
+    LerpReport, Lerpable, Quaternion, Rotation2,
+};
+
+type Vec3 = [f64; 3];
+
+fn dot(a: Vec3, b: Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn scale(a: Vec3, s: f64) -> Vec3 {
+    a.map(|x| x * s)
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn length(a: Vec3) -> f64 {
+    dot(a, a).sqrt()
+}
+
+// lengths below this, relative to the longest column, count as squashed to nothing
+const DEGENERATE: f64 = 1e-9;
+
+fn lerp_each<const N: usize>(a: [f32; N], b: [f32; N], pct: f64) -> [f32; N] {
+    std::array::from_fn(|i| lerp_f64(a[i] as f64, b[i] as f64, pct) as f32)
+}
+
+// the unit axis that's furthest from all of `axes`, with them projected out
+fn fallback_axis(axes: &[Vec3]) -> Vec3 {
+    let candidates = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    let mut best = candidates[0];
+    let mut best_len = -1.0;
+    for c in candidates {
+        let v = axes.iter().fold(c, |v, a| sub(v, scale(*a, dot(*a, v))));
+        // strictly longer, so ties go to the first
+        if length(v) > best_len + DEGENERATE {
+            best = v;
+            best_len = length(v);
+        }
+    }
+    scale(best, 1.0 / best_len)
+}
+
+// rotation matrix columns, from a unit quaternion
+fn quaternion_to_cols(q: Quaternion) -> [Vec3; 3] {
+    let q = q.normalized();
+    let [w, x, y, z] = [q.w, q.x, q.y, q.z].map(|x| x as f64);
+    [
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + w * z),
+            2.0 * (x * z - w * y),
+        ],
+        [
+            2.0 * (x * y - w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + w * x),
+        ],
+        [
+            2.0 * (x * z + w * y),
+            2.0 * (y * z - w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ]
+}
+
+// the other way, from the largest of the four to avoid dividing by something small
+fn cols_to_quaternion(m: [Vec3; 3]) -> Quaternion {
+    let at = |row: usize, col: usize| m[col][row];
+    let trace = at(0, 0) + at(1, 1) + at(2, 2);
+    let [w, x, y, z] = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        [
+            s / 4.0,
+            (at(2, 1) - at(1, 2)) / s,
+            (at(0, 2) - at(2, 0)) / s,
+            (at(1, 0) - at(0, 1)) / s,
+        ]
+    } else if at(0, 0) > at(1, 1) && at(0, 0) > at(2, 2) {
+        let s = (1.0 + at(0, 0) - at(1, 1) - at(2, 2)).sqrt() * 2.0;
+        [
+            (at(2, 1) - at(1, 2)) / s,
+            s / 4.0,
+            (at(0, 1) + at(1, 0)) / s,
+            (at(0, 2) + at(2, 0)) / s,
+        ]
+    } else if at(1, 1) > at(2, 2) {
+        let s = (1.0 + at(1, 1) - at(0, 0) - at(2, 2)).sqrt() * 2.0;
+        [
+            (at(0, 2) - at(2, 0)) / s,
+            (at(0, 1) + at(1, 0)) / s,
+            s / 4.0,
+            (at(1, 2) + at(2, 1)) / s,
+        ]
+    } else {
+        let s = (1.0 + at(2, 2) - at(0, 0) - at(1, 1)).sqrt() * 2.0;
+        [
+            (at(1, 0) - at(0, 1)) / s,
+            (at(0, 2) + at(2, 0)) / s,
+            (at(1, 2) + at(2, 1)) / s,
+            s / 4.0,
+        ]
+    };
+    // keep w positive, so the same matrix always gives the same quaternion
+    let sign = if w < 0.0 { -1.0 } else { 1.0 };
+    Quaternion::new(
+        (w * sign) as f32,
+        (x * sign) as f32,
+        (y * sign) as f32,
+        (z * sign) as f32,
+    )
+    .normalized()
+}
+
+// 2D translation, rotation, scale, and shear (x by y)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2 {
+    pub translation: [f32; 2],
+    pub rotation: Rotation2,
+    pub scale: [f32; 2],
+    pub shear: f32,
+}
+
+// 3D translation, rotation, scale, and shear ([x by y, x by z, y by z])
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform3 {
+    pub translation: [f32; 3],
+    pub rotation: Quaternion,
+    pub scale: [f32; 3],
+    pub shear: [f32; 3],
+}
+
+// 3x3, for 2D, as columns
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3(pub [[f32; 3]; 3]);
+
+// 4x4, for 3D, as columns
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4(pub [[f32; 4]; 4]);
+
+impl Default for Transform2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Transform3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform2 {
+    pub const IDENTITY: Self = Self {
+        translation: [0.0; 2],
+        rotation: Rotation2::IDENTITY,
+        scale: [1.0; 2],
+        shear: 0.0,
+    };
+
+    pub fn new(translation: [f32; 2], rotation: Rotation2, scale: [f32; 2]) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+            shear: 0.0,
+        }
+    }
+
+    pub fn with_shear(self, shear: f32) -> Self {
+        Self { shear, ..self }
+    }
+
+    // the 2x2 part, as columns
+    fn linear(&self) -> [[f64; 2]; 2] {
+        let r = self.rotation.cos_sin();
+        let [sx, sy] = self.scale.map(|x| x as f64);
+        let k = self.shear as f64;
+        let r0 = [r[0], r[1]];
+        let r1 = [-r[1], r[0]];
+        // column j is sum over i of (scale + shear)[i][j] * r_i
+        [
+            [r0[0] * sx, r0[1] * sx],
+            [r0[0] * k + r1[0] * sy, r0[1] * k + r1[1] * sy],
+        ]
+    }
+
+    pub fn to_matrix(&self) -> Mat3 {
+        let [c0, c1] = self.linear().map(|c| c.map(|x| x as f32));
+        let [tx, ty] = self.translation;
+        Mat3([[c0[0], c0[1], 0.0], [c1[0], c1[1], 0.0], [tx, ty, 1.0]])
+    }
+
+    // ignores the bottom row
+    pub fn from_matrix(m: &Mat3) -> Self {
+        let c0 = [m.0[0][0] as f64, m.0[0][1] as f64, 0.0];
+        let c1 = [m.0[1][0] as f64, m.0[1][1] as f64, 0.0];
+        let max = length(c0).max(length(c1));
+
+        let squashed = |c: Vec3| max == 0.0 || length(c) <= max * DEGENERATE;
+
+        let r0 = [c0, c1]
+            .into_iter()
+            .find(|c| !squashed(*c))
+            .map_or([1.0, 0.0, 0.0], |c| scale(c, 1.0 / length(c)));
+        // always a proper rotation, so a reflection ends up as a negative y scale
+        let r1 = [-r0[1], r0[0], 0.0];
+        let sx = if squashed(c0) { 0.0 } else { dot(r0, c0) };
+        let shear = dot(r0, c1);
+        let sy = dot(r1, c1);
+
+        Self {
+            translation: [m.0[2][0], m.0[2][1]],
+            rotation: Rotation2 {
+                cos: r0[0] as f32,
+                sin: r0[1] as f32,
+            },
+            scale: [sx as f32, sy as f32],
+            shear: shear as f32,
+        }
+    }
+
+    // rotates, scales, and shears a point, and moves it
+    pub fn apply(&self, p: [f32; 2]) -> [f32; 2] {
+        let [c0, c1] = self.linear();
+        let [x, y] = p.map(|x| x as f64);
+        [
+            (c0[0] * x + c1[0] * y) as f32 + self.translation[0],
+            (c0[1] * x + c1[1] * y) as f32 + self.translation[1],
+        ]
+    }
+}
+
+impl Transform3 {
+    pub const IDENTITY: Self = Self {
+        translation: [0.0; 3],
+        rotation: Quaternion::IDENTITY,
+        scale: [1.0; 3],
+        shear: [0.0; 3],
+    };
+
+    pub fn new(translation: [f32; 3], rotation: Quaternion, scale: [f32; 3]) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+            shear: [0.0; 3],
+        }
+    }
+
+    pub fn with_shear(self, shear: [f32; 3]) -> Self {
+        Self { shear, ..self }
+    }
+
+    // the 3x3 part, as columns
+    fn linear(&self) -> [Vec3; 3] {
+        let r = quaternion_to_cols(self.rotation);
+        let [sx, sy, sz] = self.scale.map(|x| x as f64);
+        let [kxy, kxz, kyz] = self.shear.map(|x| x as f64);
+        // (scale + shear) is upper triangular, as rows
+        let u = [[sx, kxy, kxz], [0.0, sy, kyz], [0.0, 0.0, sz]];
+        std::array::from_fn(|j| {
+            (0..3).fold([0.0; 3], |col, i| {
+                let v = scale(r[i], u[i][j]);
+                [col[0] + v[0], col[1] + v[1], col[2] + v[2]]
+            })
+        })
+    }
+
+    pub fn to_matrix(&self) -> Mat4 {
+        let [c0, c1, c2] = self.linear().map(|c| c.map(|x| x as f32));
+        let [tx, ty, tz] = self.translation;
+        Mat4([
+            [c0[0], c0[1], c0[2], 0.0],
+            [c1[0], c1[1], c1[2], 0.0],
+            [c2[0], c2[1], c2[2], 0.0],
+            [tx, ty, tz, 1.0],
+        ])
+    }
+
+    // ignores the bottom row
+    pub fn from_matrix(m: &Mat4) -> Self {
+        let col = |j: usize| [m.0[j][0] as f64, m.0[j][1] as f64, m.0[j][2] as f64];
+        let (c0, c1, c2) = (col(0), col(1), col(2));
+        let max = length(c0).max(length(c1)).max(length(c2));
+        let squashed = |c: Vec3| max == 0.0 || length(c) <= max * DEGENERATE;
+        let unit = |c: Vec3| scale(c, 1.0 / length(c));
+        // projects `axes` out of each column in turn, and takes the first one left over
+        let next_axis = |axes: &[Vec3], cols: &[Vec3]| {
+            cols.iter()
+                .map(|c| axes.iter().fold(*c, |c, a| sub(c, scale(*a, dot(*a, c)))))
+                .find(|c| !squashed(*c))
+                .map_or_else(|| fallback_axis(axes), unit)
+        };
+
+        // gram-schmidt, so m = r * u with u upper triangular
+        let r0 = next_axis(&[], &[c0, c1, c2]);
+        let r1 = next_axis(&[r0], &[c1, c2]);
+        // always a proper rotation, so a reflection ends up as a negative z scale
+        let r2 = cross(r0, r1);
+        let sx = if squashed(c0) { 0.0 } else { dot(r0, c0) };
+        let sy = if squashed(sub(c1, scale(r0, dot(r0, c1)))) {
+            0.0
+        } else {
+            dot(r1, c1)
+        };
+
+        Self {
+            translation: [m.0[3][0], m.0[3][1], m.0[3][2]],
+            rotation: cols_to_quaternion([r0, r1, r2]),
+            scale: [sx, sy, dot(r2, c2)].map(|x| x as f32),
+            shear: [dot(r0, c1), dot(r0, c2), dot(r1, c2)].map(|x| x as f32),
+        }
+    }
+
+    // rotates, scales, and shears a point, and moves it
+    pub fn apply(&self, p: [f32; 3]) -> [f32; 3] {
+        let [c0, c1, c2] = self.linear();
+        let [x, y, z] = p.map(|x| x as f64);
+        std::array::from_fn(|i| (c0[i] * x + c1[i] * y + c2[i] * z) as f32 + self.translation[i])
+    }
+}
+
+impl Mat3 {
+    pub const IDENTITY: Self = Self([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    fn bottom_row(&self) -> [f32; 3] {
+        self.0.map(|c| c[2])
+    }
+}
+
+impl Mat4 {
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    fn bottom_row(&self) -> [f32; 4] {
+        self.0.map(|c| c[3])
+    }
+}
+
+impl From<Transform2> for Mat3 {
+    fn from(t: Transform2) -> Self {
+        t.to_matrix()
+    }
+}
+
+impl From<Mat3> for Transform2 {
+    fn from(m: Mat3) -> Self {
+        Transform2::from_matrix(&m)
+    }
+}
+
+impl From<Transform3> for Mat4 {
+    fn from(t: Transform3) -> Self {
+        t.to_matrix()
+    }
+}
+
+impl From<Mat4> for Transform3 {
+    fn from(m: Mat4) -> Self {
+        Transform3::from_matrix(&m)
+    }
+}
+
+impl Transform2 {
+    fn lerp_inner<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+        let p = pct.lerp_pct();
+        Self {
+            translation: lerp_each(self.translation, other.translation, p),
+            rotation: self.rotation.lerpify(&other.rotation, pct),
+            scale: lerp_each(self.scale, other.scale, p),
+            shear: lerp_f64(self.shear as f64, other.shear as f64, p) as f32,
+        }
+    }
+}
+
+impl Transform3 {
+    fn lerp_inner<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+        let p = pct.lerp_pct();
+        Self {
+            translation: lerp_each(self.translation, other.translation, p),
+            rotation: self.rotation.lerpify(&other.rotation, pct),
+            scale: lerp_each(self.scale, other.scale, p),
+            shear: lerp_each(self.shear, other.shear, p),
+        }
+    }
+}
+
+impl Mat3 {
+    fn lerp_inner<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+        let t = Transform2::from_matrix(self).lerp_inner(&Transform2::from_matrix(other), pct);
+        let mut m = t.to_matrix();
+        let bottom = lerp_each(self.bottom_row(), other.bottom_row(), pct.lerp_pct());
+        for (c, b) in m.0.iter_mut().zip(bottom) {
+            c[2] = b;
+        }
+        m
+    }
+}
+
+impl Mat4 {
+    fn lerp_inner<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+        let t = Transform3::from_matrix(self).lerp_inner(&Transform3::from_matrix(other), pct);
+        let mut m = t.to_matrix();
+        let bottom = lerp_each(self.bottom_row(), other.bottom_row(), pct.lerp_pct());
+        for (c, b) in m.0.iter_mut().zip(bottom) {
+            c[3] = b;
+        }
+        m
+    }
+}
+
+impl Finite for Transform2 {
+    fn is_finite(&self) -> bool {
+        self.rotation.is_finite()
+            && [self.shear]
+                .iter()
+                .chain(&self.translation)
+                .chain(&self.scale)
+                .all(|x| x.is_finite())
+    }
+}
+
+impl Finite for Transform3 {
+    fn is_finite(&self) -> bool {
+        self.rotation.is_finite()
+            && self
+                .translation
+                .iter()
+                .chain(&self.scale)
+                .chain(&self.shear)
+                .all(|x| x.is_finite())
+    }
+}
+
+impl Finite for Mat3 {
+    fn is_finite(&self) -> bool {
+        self.0.iter().flatten().all(|x| x.is_finite())
+    }
+}
+
+impl Finite for Mat4 {
+    fn is_finite(&self) -> bool {
+        self.0.iter().flatten().all(|x| x.is_finite())
+    }
+}
+
+macro_rules! impl_lerpable_transform {
+    ($t:ident) => {
+        impl Lerpable for $t {
+            fn lerpify<T: IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                with_exact_ends(self, other, pct.lerp_pct(), || self.lerp_inner(other, pct))
+            }
+
+            fn lerp_plan() -> LerpPlan {
+                LerpPlan::new(stringify!($t), LerpPlanKind::Lerp)
+            }
+
+            fn lerp_diff(&self, other: &Self, path: &FieldPath, report: &mut LerpReport) {
+                report.add(path.clone(), LerpHow::Lerp, Some(self != other))
+            }
+        }
+
+        impl_try_lerpable_finite!($t);
+    };
+}
+
+impl_lerpable_transform!(Transform2);
+impl_lerpable_transform!(Transform3);
+impl_lerpable_transform!(Mat3);
+impl_lerpable_transform!(Mat4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close<const N: usize>(a: [[f32; N]; N], b: [[f32; N]; N]) {
+        let err = a
+            .iter()
+            .flatten()
+            .zip(b.iter().flatten())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max);
+        assert!(err < 1e-5, "{a:?} != {b:?}");
+    }
+
+    fn assert_round_trips3(m: Mat3) {
+        assert_close(Transform2::from_matrix(&m).to_matrix().0, m.0);
+        for pct in [0.001, 0.5, 0.999] {
+            assert_close(m.lerpify(&m, &pct).0, m.0);
+        }
+    }
+
+    fn assert_round_trips4(m: Mat4) {
+        assert_close(Transform3::from_matrix(&m).to_matrix().0, m.0);
+        for pct in [0.001, 0.5, 0.999] {
+            assert_close(m.lerpify(&m, &pct).0, m.0);
+        }
+    }
+
+    #[test]
+    fn mat3_round_trips() {
+        let t = Transform2::new([1.0, 2.0], Rotation2::from_angle(2.5), [2.0, 0.5]);
+        assert_round_trips3(t.with_shear(0.4).to_matrix());
+        // reflected
+        assert_round_trips3(Mat3([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [3.0, 0.0, 1.0]]));
+        assert_round_trips3(Mat3([[0.0, 2.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]));
+        // singular
+        assert_round_trips3(Mat3([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]));
+        assert_round_trips3(Mat3([[1.0, 1.0, 0.0], [2.0, 2.0, 0.0], [0.0, 0.0, 1.0]]));
+        assert_round_trips3(Mat3([[0.0; 3], [0.0; 3], [5.0, 6.0, 1.0]]));
+    }
+
+    #[test]
+    fn mat4_round_trips() {
+        let t = Transform3::new(
+            [1.0, 2.0, 3.0],
+            Quaternion::from_axis_angle([1.0, 2.0, 0.5], 2.0),
+            [2.0, 0.5, 3.0],
+        );
+        assert_round_trips4(t.with_shear([0.3, -0.2, 0.7]).to_matrix());
+        let reflected = Transform3 {
+            scale: [2.0, -0.5, 3.0],
+            ..t
+        };
+        assert_round_trips4(reflected.to_matrix());
+        let c = |x: [f32; 3]| [x[0], x[1], x[2], 0.0];
+        let m = |c0, c1, c2| Mat4([c(c0), c(c1), c(c2), [1.0, 2.0, 3.0, 1.0]]);
+        // singular
+        assert_round_trips4(m([0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]));
+        assert_round_trips4(m([1.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]));
+        assert_round_trips4(m([0.0; 3], [0.0; 3], [0.0, 0.0, 2.0]));
+        assert_round_trips4(m([1.0, 1.0, 1.0], [2.0, 2.0, 2.0], [-1.0, -1.0, -1.0]));
+        assert_round_trips4(m([0.0; 3], [0.0; 3], [0.0; 3]));
+    }
+
+    #[test]
+    fn reflection_lerps_through_flat() {
+        let mirrored = Transform2::new([0.0, 0.0], Rotation2::IDENTITY, [-1.0, 1.0]);
+        let t = Transform2::from_matrix(&mirrored.to_matrix());
+        // the reflection moves to y, with a half turn
+        assert!(t.scale[1] < 0.0);
+        let halfway = Mat3::IDENTITY.lerpify(&mirrored.to_matrix(), &0.5);
+        let h = Transform2::from_matrix(&halfway);
+        assert!(h.scale[1].abs() < 1e-6, "{h:?}");
+    }
+
+    #[test]
+    fn decomposing_is_deterministic() {
+        let flat = Mat4([
+            [1.0, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ]);
+        let t = Transform3::from_matrix(&flat);
+        assert_eq!(t, Transform3::from_matrix(&flat));
+        assert_eq!(t.rotation, Quaternion::IDENTITY);
+        assert_eq!(t.scale, [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn endpoints_are_exact() {
+        let a = Mat4::IDENTITY;
+        let b = Transform3::new(
+            [1.0, 2.0, 3.0],
+            Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0),
+            [2.0, 2.0, 2.0],
+        )
+        .to_matrix();
+        assert_eq!(a.lerpify(&b, &0.0), a);
+        assert_eq!(a.lerpify(&b, &1.0), b);
+    }
+}
@@ -0,0 +1,56 @@
+// the derive's generated generics shouldn't clash with the user's own type names
+
+use lerpable::Lerpable;
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+enum M {
+    A(f32),
+    B,
+}
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+#[lerpable(shared_fields)]
+enum T {
+    Dot { center: f32 },
+    Label { center: f32, size: f32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Lerpable)]
+#[lerpable(ordinal)]
+enum LerpMethod {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+struct Uses {
+    m: M,
+    t: T,
+    size: LerpMethod,
+}
+
+#[test]
+fn enums_can_share_names_with_generics() {
+    assert_eq!(M::A(0.0).lerpify(&M::A(2.0), &0.5), M::A(1.0));
+    assert_eq!(M::A(0.0).lerpify(&M::B, &0.75), M::B);
+
+    let dot = T::Dot { center: 0.0 };
+    let label = T::Label {
+        center: 4.0,
+        size: 1.0,
+    };
+    assert_eq!(dot.lerpify(&label, &0.25), T::Dot { center: 1.0 });
+
+    let small = Uses {
+        m: M::B,
+        t: dot,
+        size: LerpMethod::Small,
+    };
+    let large = Uses {
+        m: M::B,
+        t: label,
+        size: LerpMethod::Large,
+    };
+    assert_eq!(small.lerpify(&large, &0.5).size, LerpMethod::Medium);
+}
@@ -0,0 +1,64 @@
+// with and angle fields don't go through try_lerpify, but a fallible derive still checks them
+
+use lerpable::{LerpErrorReason, Lerpable, TryLerpable};
+
+mod twice {
+    use lerpable::IsLerpingMethod;
+
+    // overshoots, so it can make an infinity out of finite ends
+    pub fn lerpify<T: IsLerpingMethod>(this: &f32, other: &f32, pct: &T) -> f32 {
+        lerpable::lerp_f32(*this, *other, pct.lerp_pct_f32()) * 2.0
+    }
+
+    pub use lerpable::pop_in as lerp_partial;
+}
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+#[lerpable(fallible)]
+struct Camera {
+    #[lerpable(angle(degrees))]
+    heading: f32,
+    #[lerpable(with = "twice")]
+    zoom: f32,
+    #[lerpable(func = "lerpable::step")]
+    raw: f32,
+}
+
+fn camera(heading: f32, zoom: f32) -> Camera {
+    Camera {
+        heading,
+        zoom,
+        raw: f32::NAN,
+    }
+}
+
+#[test]
+fn finite_with_and_angle_fields_are_fine() {
+    let (start, end) = (camera(350.0, 1.0), camera(10.0, 2.0));
+    let lerped = start.try_lerpify(&end, &0.5).unwrap();
+    assert_eq!(lerped.heading, 360.0);
+    assert_eq!(lerped.zoom, 3.0);
+    // func fields are up to the func
+    assert!(lerped.raw.is_nan());
+}
+
+#[test]
+fn nan_angles_are_errors() {
+    let err = camera(f32::NAN, 1.0)
+        .try_lerpify(&camera(10.0, 1.0), &0.5)
+        .unwrap_err();
+    assert_eq!(err.path.to_string(), "heading");
+    assert_eq!(err.reason, LerpErrorReason::NonFinite { type_name: "f32" });
+}
+
+#[test]
+fn non_finite_with_results_are_errors() {
+    let err = camera(0.0, f32::MAX)
+        .try_lerpify(&camera(0.0, f32::MAX), &0.5)
+        .unwrap_err();
+    assert_eq!(err.path.to_string(), "zoom");
+    assert_eq!(err.reason, LerpErrorReason::NonFinite { type_name: "f32" });
+    // the infallible lerp still goes through
+    let lerped = camera(0.0, f32::MAX).lerpify(&camera(0.0, f32::MAX), &0.5);
+    assert_eq!(lerped.zoom, f32::INFINITY);
+}
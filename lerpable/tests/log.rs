@@ -0,0 +1,53 @@
+// #[lerpable(log)] spaces numbers evenly in log space, wherever they are in the field
+
+use lerpable::Lerpable;
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+struct Zoom {
+    #[lerpable(log)]
+    level: f64,
+    #[lerpable(log)]
+    octaves: Vec<f64>,
+    #[lerpable(log)]
+    focus: Option<f32>,
+    #[lerpable(log)]
+    hz: u32,
+    linear: f64,
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-4 * b.abs().max(1.0)
+}
+
+#[test]
+fn log_fields_pass_the_geometric_mean() {
+    let start = Zoom {
+        level: 1.0,
+        octaves: vec![1.0, 10.0],
+        focus: Some(2.0),
+        hz: 100,
+        linear: 1.0,
+    };
+    let end = Zoom {
+        level: 100.0,
+        octaves: vec![100.0, 1000.0],
+        focus: Some(8.0),
+        hz: 10000,
+        linear: 100.0,
+    };
+    let mid = start.lerpify(&end, &0.5);
+
+    assert!(close(mid.level, 10.0), "{mid:?}");
+    assert!(
+        close(mid.octaves[0], 10.0) && close(mid.octaves[1], 100.0),
+        "{mid:?}"
+    );
+    assert!(close(mid.focus.unwrap() as f64, 4.0), "{mid:?}");
+    assert_eq!(mid.hz, 1000);
+    // fields without it are still linear
+    assert_eq!(mid.linear, 50.5);
+
+    // and the ends are exact
+    assert_eq!(start.lerpify(&end, &0.0), start);
+    assert_eq!(start.lerpify(&end, &1.0), end);
+}
@@ -0,0 +1,87 @@
+// field attributes that change how a field lerps should show up in plans and reports
+
+use lerpable::{FieldAttr, KeepSide, LerpHow, LerpPlan, LerpReport, Lerpable, RoundingMode};
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+struct Inner {
+    x: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Lerpable)]
+struct Attrs {
+    #[lerpable(keep = "start")]
+    k: f32,
+    #[lerpable(step_at = 0.25)]
+    s: f32,
+    #[lerpable(round = "floor")]
+    r: i32,
+    #[lerpable(overflow = "wrap")]
+    o: u8,
+    #[lerpable(log)]
+    l: f64,
+    #[lerpable(clamp(min = 0.0, max = 1.0))]
+    c: f32,
+    #[lerpable(keep = "end")]
+    inner: Inner,
+}
+
+fn attrs(k: f32) -> Attrs {
+    Attrs {
+        k,
+        s: k,
+        r: k as i32,
+        o: k as u8,
+        l: k as f64 + 1.0,
+        c: k,
+        inner: Inner { x: k },
+    }
+}
+
+#[test]
+fn plans_show_field_attrs() {
+    let plan = LerpPlan::of::<Attrs>().to_string();
+    for shown in [
+        "k [keep = start] -> f32",
+        "s [step_at = 0.25] -> f32",
+        "r [round = floor] -> i32",
+        "o [overflow = wrap] -> u8",
+        "l [log] -> f64",
+        "c [clamp(min = 0.0, max = 1.0)] -> f32",
+        "inner [keep = end] -> plan::Inner",
+    ] {
+        assert!(plan.contains(shown), "{:?} isn't in\n{}", shown, plan);
+    }
+}
+
+#[test]
+fn reports_show_field_attrs() {
+    let report = LerpReport::between(&attrs(0.0), &attrs(1.0));
+    let entry = |path: &str| {
+        report
+            .entries()
+            .iter()
+            .find(|x| x.path.to_string() == path)
+            .unwrap()
+            .clone()
+    };
+
+    // keep isn't a lerp, even though the field differs
+    assert_eq!(entry("k").how, LerpHow::Keep(KeepSide::Start));
+    assert_eq!(entry("k").to_string(), "k: keep start (differs)");
+    assert_eq!(entry("inner.x").how, LerpHow::Keep(KeepSide::End));
+
+    assert_eq!(entry("s").attrs, vec![FieldAttr::StepAt(0.25)]);
+    assert_eq!(
+        entry("r").attrs,
+        vec![FieldAttr::Round(RoundingMode::Floor)]
+    );
+    assert_eq!(
+        entry("o").to_string(),
+        "o: lerp [overflow = wrap] (differs)"
+    );
+    assert_eq!(entry("l").attrs, vec![FieldAttr::Log]);
+    assert_eq!(
+        entry("c").to_string(),
+        "c: lerp [clamp(min = 0.0, max = 1.0)] (differs)"
+    );
+}
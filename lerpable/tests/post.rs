@@ -0,0 +1,28 @@
+// `post` should apply however a value is made, including elements popping in or out of a Vec
+
+use lerpable::Lerpable;
+
+#[derive(Debug, Clone, Copy, PartialEq, Lerpable)]
+#[lerpable(ordinal, post = "Size::at_least_medium")]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Size {
+    fn at_least_medium(self) -> Self {
+        match self {
+            Size::Small => Size::Medium,
+            x => x,
+        }
+    }
+}
+
+#[test]
+fn post_applies_to_ordinal_enums() {
+    assert_eq!(Size::Small.lerpify(&Size::Small, &0.0), Size::Medium);
+
+    let grown = vec![Size::Large].lerpify(&vec![Size::Large, Size::Small], &1.0);
+    assert_eq!(grown, vec![Size::Large, Size::Medium]);
+}
@@ -1,10 +1,16 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::parser::*;
 
 pub(crate) struct FieldTokensLerpable {
     pub(crate) for_lerpable: TokenStream2,
+    pub(crate) for_lerpable_mut: TokenStream2,
+    pub(crate) for_plan: TokenStream2,
+    pub(crate) for_diff: TokenStream2,
+    pub(crate) for_variant_name: TokenStream2, // only used by enums
+    pub(crate) for_lerp_partial: TokenStream2,
+    pub(crate) for_try_lerpable: TokenStream2, // only used with fallible
 }
 impl GenFinal for FieldTokensLerpable {
     // Something(f32)
@@ -15,13 +21,54 @@ impl GenFinal for FieldTokensLerpable {
         let name = idents.name;
 
         let for_lerpable = variants.iter().map(|x| x.for_lerpable.clone());
+        let for_lerpable_mut = variants.iter().map(|x| x.for_lerpable_mut.clone());
+        let for_plan = variants.iter().map(|x| x.for_plan.clone());
+        let for_diff = variants.iter().map(|x| x.for_diff.clone());
+        let for_lerp_partial = variants.iter().map(|x| x.for_lerp_partial.clone());
 
-        quote! {
-            impl lerpable::Lerpable for #name {
-                fn lerpify<T: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
-                    #name(#(#for_lerpable,)*)
+        let lerpify = with_post(&idents.post, quote! { Self(#(#for_lerpable,)*) });
+        let lerpify_mut = with_post(&idents.post, quote! { Self(#(#for_lerpable_mut,)*) });
+        let lerp_partial = with_post(&idents.post, quote! { Self(#(#for_lerp_partial,)*) });
+        let for_try_lerpable = variants.iter().map(|x| x.for_try_lerpable.clone());
+        let try_lerpable_impl = try_lerpable_impl(
+            &name,
+            idents.fallible,
+            with_post(&idents.post, quote! { Self(#(#for_try_lerpable,)*) }),
+        );
+        let (lerpable_trait, target) = lerpable_target(&name, &idents.remote);
+
+        let lerpable_impl = quote! {
+            impl #lerpable_trait for #target {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    #lerpify
+                }
+
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self {
+                    #lerpify_mut
+                }
+
+                #[allow(unused_variables)]
+                fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, pct: __LerpT) -> Self {
+                    #lerp_partial
+                }
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(
+                        std::any::type_name::<Self>(),
+                        lerpable::LerpPlanKind::Struct(vec![#(#for_plan,)*]),
+                    )
+                }
+
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    #(#for_diff)*
                 }
             }
+        };
+
+        let lerpable_impl = with_remote(&name, &idents.remote, lerpable_impl);
+        quote! {
+            #lerpable_impl
+            #try_lerpable_impl
         }
     }
 
@@ -32,15 +79,56 @@ impl GenFinal for FieldTokensLerpable {
         let name = idents.name;
 
         let for_lerpable = variants.iter().map(|a| a.for_lerpable.clone());
+        let for_lerpable_mut = variants.iter().map(|x| x.for_lerpable_mut.clone());
+        let for_plan = variants.iter().map(|x| x.for_plan.clone());
+        let for_diff = variants.iter().map(|x| x.for_diff.clone());
+        let for_lerp_partial = variants.iter().map(|x| x.for_lerp_partial.clone());
 
-        quote! {
-            impl lerpable::Lerpable for #name {
-                fn lerpify<T: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
-                    #name {
-                        #(#for_lerpable,)*
-                    }
+        let lerpify = with_post(&idents.post, quote! { Self { #(#for_lerpable,)* } });
+        let lerpify_mut = with_post(&idents.post, quote! { Self { #(#for_lerpable_mut,)* } });
+        let lerp_partial = with_post(&idents.post, quote! { Self { #(#for_lerp_partial,)* } });
+        let for_try_lerpable = variants.iter().map(|x| x.for_try_lerpable.clone());
+        let try_lerpable_impl = try_lerpable_impl(
+            &name,
+            idents.fallible,
+            with_post(&idents.post, quote! { Self { #(#for_try_lerpable,)* } }),
+        );
+        let (lerpable_trait, target) = lerpable_target(&name, &idents.remote);
+
+        let lerpable_impl = quote! {
+            impl #lerpable_trait for #target {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    #lerpify
+                }
+
+                #[allow(unused_variables)]
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self {
+                    #lerpify_mut
+                }
+
+                #[allow(unused_variables)]
+                fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, pct: __LerpT) -> Self {
+                    #lerp_partial
+                }
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(
+                        std::any::type_name::<Self>(),
+                        lerpable::LerpPlanKind::Struct(vec![#(#for_plan,)*]),
+                    )
+                }
+
+                #[allow(unused_variables)]
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    #(#for_diff)*
                 }
             }
+        };
+
+        let lerpable_impl = with_remote(&name, &idents.remote, lerpable_impl);
+        quote! {
+            #lerpable_impl
+            #try_lerpable_impl
         }
     }
 
@@ -51,105 +139,838 @@ impl GenFinal for FieldTokensLerpable {
         let name = idents.name;
 
         let for_lerpable = variants.iter().map(|a| a.for_lerpable.clone());
+        let for_lerpable_mut = variants.iter().map(|x| x.for_lerpable_mut.clone());
+        let for_plan = variants.iter().map(|x| x.for_plan.clone());
+        let for_diff = variants.iter().map(|x| x.for_diff.clone());
+        let for_variant_name = variants
+            .iter()
+            .map(|x| x.for_variant_name.clone())
+            .collect::<Vec<_>>();
+        let for_lerp_partial = variants.iter().map(|x| x.for_lerp_partial.clone());
+
+        // what to do if the variants don't match, with the method in `pct`
+        let (on_change, on_mismatch, lerp_partial) = if idents.fade {
+            (
+                quote! { lerpable::VariantChangePlan::Fade },
+                quote! {
+                    {
+                        // first half fades the old one out, second half fades the new one in
+                        let p = lerpable::IsLerpingMethod::lerp_pct(pct);
+                        if lerpable::IsLerpingMethod::has_lerp_stepped(pct) {
+                            lerpable::Lerpable::lerp_partial(other, lerpable::IsLerpingMethod::with_lerp_pct(pct, 2.0 * p - 1.0))
+                        } else {
+                            lerpable::Lerpable::lerp_partial(self, lerpable::IsLerpingMethod::with_lerp_pct(pct, 1.0 - 2.0 * p))
+                        }
+                    }
+                },
+                // and so the variants fade using whatever's inside them
+                {
+                    let lerp_partial = with_post(
+                        &idents.post,
+                        quote! { match self { #(#for_lerp_partial,)* } },
+                    );
+                    quote! {
+                        fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, pct: __LerpT) -> Self {
+                            #lerp_partial
+                        }
+                    }
+                },
+            )
+        } else if let Some(cross_str) = &idents.cross {
+            let cross: syn::Path = syn::parse_str(cross_str)
+                .unwrap_or_else(|_| panic!("Custom cross {} is invalid path!", cross_str));
+            (
+                quote! { lerpable::VariantChangePlan::Cross(#cross_str) },
+                quote! { #cross(self, other, pct) },
+                post_lerp_partial(&idents.post),
+            )
+        } else if !idents.shared.is_empty() {
+            let shared_names = idents.shared.iter().map(|x| x.name.to_string());
+            let shared_lerps = idents.shared.iter().map(|x| {
+                let lerpify =
+                    x.data
+                        .to_lerpify(&x.name.to_string(), quote! { self_f }, quote! { other_f });
+                let find = shared_field_finder(&name, x, idents.variant_count);
+                quote! {
+                    {
+                        #find
+                        if let (Some(self_f), Some(other_f)) = (find(self), find(other)) {
+                            let value = #lerpify;
+                            if let Some(result_f) = find_mut(&mut result) {
+                                *result_f = value;
+                            }
+                        }
+                    }
+                }
+            });
+            (
+                quote! { lerpable::VariantChangePlan::StepSharing(&[#(#shared_names,)*]) },
+                quote! {
+                    {
+                        // step, and then write over the fields the variants have in common
+                        let mut result = lerpable::step(self, other, pct);
+                        #(#shared_lerps)*
+                        result
+                    }
+                },
+                post_lerp_partial(&idents.post),
+            )
+        } else {
+            (
+                quote! { lerpable::VariantChangePlan::Step },
+                quote! { lerpable::step(self, other, pct) },
+                post_lerp_partial(&idents.post),
+            )
+        };
+
+        let lerpify = with_post(
+            &idents.post,
+            quote! {
+                match (self, other) {
+                    #(#for_lerpable,)*
+                    _ => #on_mismatch
+                }
+            },
+        );
+        let lerpify_mut = with_post(
+            &idents.post,
+            quote! {
+                match (self, other) {
+                    #(#for_lerpable_mut,)*
+                    _ => {
+                        let pct = &lerpable::IsLerpingMethodMut::next_leaf(method);
+                        #on_mismatch
+                    }
+                }
+            },
+        );
+
+        // with fallible, a mismatch that would step is an error instead
+        let for_try_lerpable = variants.iter().map(|x| x.for_try_lerpable.clone());
+        let try_on_mismatch = if idents.fade || idents.cross.is_some() || !idents.shared.is_empty()
+        {
+            on_mismatch.clone()
+        } else {
+            quote! {
+                {
+                    let variant_name = |x: &Self| match x {
+                        #(#for_variant_name,)*
+                    };
+                    return Err(lerpable::LerpError::new(lerpable::LerpErrorReason::VariantMismatch {
+                        from: variant_name(self),
+                        to: variant_name(other),
+                    }));
+                }
+            }
+        };
+        let try_lerpable_impl = try_lerpable_impl(
+            &name,
+            idents.fallible,
+            with_post(
+                &idents.post,
+                quote! {
+                    match (self, other) {
+                        #(#for_try_lerpable,)*
+                        _ => #try_on_mismatch
+                    }
+                },
+            ),
+        );
+
+        let shared_diffs = idents.shared.iter().map(|x| {
+            let name_str = x.name.to_string();
+            let diff = x.data.to_diff(
+                quote! { path.field(#name_str) },
+                quote! { self_f },
+                quote! { other_f },
+            );
+            let find = shared_field_finder(&name, x, idents.variant_count);
+            quote! {
+                {
+                    #find
+                    if let (Some(self_f), Some(other_f)) = (find(self), find(other)) {
+                        #diff
+                    }
+                }
+            }
+        });
 
         quote! {
             impl lerpable::Lerpable for #name {
-                fn lerpify<T: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &T) -> Self {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    #lerpify
+                }
+
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self {
+                    #lerpify_mut
+                }
+
+                #lerp_partial
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(
+                        std::any::type_name::<Self>(),
+                        lerpable::LerpPlanKind::Enum {
+                            variants: vec![#(#for_plan,)*],
+                            on_change: #on_change,
+                        },
+                    )
+                }
+
+                #[allow(unused_variables)]
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    let variant_name = |x: &Self| match x {
+                        #(#for_variant_name,)*
+                    };
                     match (self, other) {
-                        #(#for_lerpable,)*
-                        _ => lerpable::step(self, other, pct)
+                        #(#for_diff,)*
+                        _ => {
+                            let how = lerpable::LerpHow::VariantChange {
+                                from: variant_name(self),
+                                to: variant_name(other),
+                                how: #on_change,
+                            };
+                            report.add(path.clone(), how, Some(true));
+                            #(#shared_diffs)*
+                        }
                     }
                 }
             }
+
+            #try_lerpable_impl
         }
     }
 
-    fn from_newtype_struct(idents: StructIdents, _parent_ident: syn::Ident) -> FieldTokensLerpable {
-        let method_def = idents.to_method_override();
+    // Size::Small | Size::Medium | Size::Large, in order
+    fn make_ordinal_enum_final(
+        idents: ParsedFieldIdent,
+        variants: Vec<syn::Ident>,
+    ) -> TokenStream2 {
+        let name = idents.name;
+        // stepping through the variants in between can't go wrong
+        let try_lerpable_impl = try_lerpable_impl(&name, idents.fallible, quote! {});
+        let variant_strs = variants.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        let indexes = 0..variants.len();
+        let lerpify = with_post(
+            &idents.post,
+            quote! {
+                match lerpable::lerp_ordinal(position(self), position(other), &positions, pct) {
+                    #(#indexes => Self::#variants,)*
+                    _ => unreachable!(),
+                }
+            },
+        );
 
-        let lerpify = if let Some(func) = idents.func() {
-            quote! { #func(&self.0, &other.0, method) }
-        } else {
-            quote! { self.0.lerpify(&other.0, method) }
-        };
+        let lerp_partial = post_lerp_partial(&idents.post);
 
-        let for_lerpable = quote! {
-            {
-                #method_def
-                #lerpify
+        quote! {
+            impl lerpable::Lerpable for #name {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    // `as` gives the explicit discriminant if there is one, or the index
+                    let position = |x: &Self| -> i128 {
+                        match x {
+                            #(Self::#variants => Self::#variants as i128,)*
+                        }
+                    };
+                    let positions = [#(Self::#variants as i128,)*];
+                    #lerpify
+                }
+
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self {
+                    lerpable::Lerpable::lerpify(self, other, &lerpable::IsLerpingMethodMut::next_leaf(method))
+                }
+
+                #lerp_partial
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(
+                        std::any::type_name::<Self>(),
+                        lerpable::LerpPlanKind::Enum {
+                            variants: vec![#(lerpable::VariantLerpPlan { name: #variant_strs, fields: vec![] },)*],
+                            on_change: lerpable::VariantChangePlan::Ordinal,
+                        },
+                    )
+                }
+
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    let variant_name = |x: &Self| match x {
+                        #(Self::#variants => #variant_strs,)*
+                    };
+                    let (from, to) = (variant_name(self), variant_name(other));
+                    if from == to {
+                        report.add(path.field(from), lerpable::LerpHow::Step, Some(false))
+                    } else {
+                        let how = lerpable::LerpHow::VariantChange {
+                            from,
+                            to,
+                            how: lerpable::VariantChangePlan::Ordinal,
+                        };
+                        report.add(path.clone(), how, Some(true))
+                    }
+                }
             }
+
+            #try_lerpable_impl
+        }
+    }
+
+    fn make_via_final(idents: ParsedFieldIdent, via: ViaIdents) -> TokenStream2 {
+        let name = idents.name;
+        let via_ty = via.ty;
+
+        let to_via = match via.to_via {
+            Some(func) => quote! { #func },
+            None => quote! { (|x: &#name| -> #via_ty { std::convert::Into::into(x.clone()) }) },
+        };
+        let post = match &idents.post {
+            Some(post) => quote! { #post },
+            None => quote! {},
         };
+        let from_via = match via.from_via {
+            Some(func) => quote! { #func },
+            None => quote! { <#name as std::convert::From<#via_ty>>::from },
+        };
+        let try_lerpable_impl = try_lerpable_impl(
+            &name,
+            idents.fallible,
+            quote! {
+                {
+                    let (this, other): (#via_ty, #via_ty) = (#to_via(self), #to_via(other));
+                    #post(#from_via(lerpable::TryLerpable::try_lerpify(&this, &other, pct)?))
+                }
+            },
+        );
+
+        quote! {
+            impl lerpable::Lerpable for #name {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    let (this, other): (#via_ty, #via_ty) = (#to_via(self), #to_via(other));
+                    #post(#from_via(lerpable::Lerpable::lerpify(&this, &other, pct)))
+                }
+
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self {
+                    let (this, other): (#via_ty, #via_ty) = (#to_via(self), #to_via(other));
+                    #post(#from_via(lerpable::Lerpable::lerpify_mut(&this, &other, method)))
+                }
+
+                fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, pct: __LerpT) -> Self {
+                    let this: #via_ty = #to_via(self);
+                    #post(#from_via(lerpable::Lerpable::lerp_partial(&this, pct)))
+                }
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(
+                        std::any::type_name::<Self>(),
+                        lerpable::LerpPlanKind::Via(<#via_ty as lerpable::Lerpable>::lerp_plan),
+                    )
+                }
+
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    let (this, other): (#via_ty, #via_ty) = (#to_via(self), #to_via(other));
+                    lerpable::Lerpable::lerp_diff(&this, &other, path, report)
+                }
+            }
+
+            #try_lerpable_impl
+        }
+    }
+
+    fn make_skip_final(idents: ParsedFieldIdent) -> TokenStream2 {
+        let name = idents.name;
+        let try_lerpable_impl = try_lerpable_impl(&name, idents.fallible, quote! {});
+
+        quote! {
+            impl lerpable::Lerpable for #name {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self {
+                    lerpable::step(self, other, pct)
+                }
+
+                fn lerp_plan() -> lerpable::LerpPlan {
+                    lerpable::LerpPlan::new(std::any::type_name::<Self>(), lerpable::LerpPlanKind::Step)
+                }
+
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    report.add(path.clone(), lerpable::LerpHow::Step, None)
+                }
+            }
+
+            #try_lerpable_impl
+        }
+    }
 
-        FieldTokensLerpable { for_lerpable }
+    fn from_newtype_struct(idents: StructIdents, _parent_ident: syn::Ident) -> FieldTokensLerpable {
+        let for_lerpable = idents.to_lerpify("0", quote! { &self.0 }, quote! { &other.0 });
+
+        let for_lerpable_mut =
+            idents.to_stateful_lerpify("0", quote! { &self.0 }, quote! { &other.0 });
+
+        let for_plan = idents.to_plan("0");
+
+        let for_diff = idents.to_diff(
+            quote! { path.field("0") },
+            quote! { &self.0 },
+            quote! { &other.0 },
+        );
+
+        let for_lerp_partial = idents.to_lerp_partial(quote! { &self.0 });
+
+        let for_try_lerpable = idents.to_try_lerpify("0", quote! { &self.0 }, quote! { &other.0 });
+
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name: quote! {},
+            for_lerp_partial,
+            for_try_lerpable,
+        }
     }
 
     // e.g. TileAxisLocs::V(TileAxisVs)
     fn from_unnamed_enum(idents: EnumIdents) -> FieldTokensLerpable {
         let variant_ident = idents.variant_ident();
-        let name = idents.enum_ident();
+        let variant_str = variant_ident.to_string();
 
         // if they're the same, lerp the struct inside. otherwise, will default to the step!
-        let for_lerpable = match idents.how_to_control_internal() {
-            HowToControlThis::Skip => quote! {
-               (#name::#variant_ident(self_s), #name::#variant_ident(other_s)) => #name::#variant_ident(lerpable::step(&self_s, &other_s, pct))
-            },
-            HowToControlThis::LerpifyType => quote! {
-                (#name::#variant_ident(self_s), #name::#variant_ident(other_s)) => #name::#variant_ident(self_s.lerpify(&other_s, pct))
-            },
+        let lerpify = idents
+            .internal()
+            .to_lerpify("0", quote! { self_s }, quote! { other_s });
+        let for_lerpable = quote! {
+            (Self::#variant_ident(self_s), Self::#variant_ident(other_s)) => {
+                let pct = &lerpable::IsLerpingMethod::enter_field(pct, #variant_str);
+                Self::#variant_ident(#lerpify)
+            }
         };
 
-        FieldTokensLerpable { for_lerpable }
+        let lerpify_mut =
+            idents
+                .internal()
+                .to_stateful_lerpify("0", quote! { self_s }, quote! { other_s });
+        let for_lerpable_mut = quote! {
+            (Self::#variant_ident(self_s), Self::#variant_ident(other_s)) => {
+                lerpable::IsLerpingMethodMut::enter_field(method, #variant_str);
+                let result = #lerpify_mut;
+                lerpable::IsLerpingMethodMut::exit(method);
+                Self::#variant_ident(result)
+            }
+        };
+
+        let field_plan = idents.internal().to_plan("0");
+        let for_plan = quote! {
+            lerpable::VariantLerpPlan { name: #variant_str, fields: vec![#field_plan] }
+        };
+
+        let field_diff = idents.internal().to_diff(
+            quote! { path.field(#variant_str).field("0") },
+            quote! { self_s },
+            quote! { other_s },
+        );
+        let for_diff = quote! {
+            (Self::#variant_ident(self_s), Self::#variant_ident(other_s)) => { #field_diff }
+        };
+
+        let for_variant_name = quote! { Self::#variant_ident(..) => #variant_str };
+
+        let lerp_partial = idents.internal().to_lerp_partial(quote! { self_s });
+        let for_lerp_partial = quote! {
+            Self::#variant_ident(self_s) => Self::#variant_ident(#lerp_partial)
+        };
+
+        let try_lerpify =
+            idents
+                .internal()
+                .to_try_lerpify("0", quote! { self_s }, quote! { other_s });
+        let for_try_lerpable = quote! {
+            (Self::#variant_ident(self_s), Self::#variant_ident(other_s)) => {
+                let pct = &lerpable::IsLerpingMethod::enter_field(pct, #variant_str);
+                (|| -> Result<Self, lerpable::LerpError> {
+                    Ok(Self::#variant_ident(#try_lerpify))
+                })()
+                .map_err(|e| e.in_field(#variant_str))?
+            }
+        };
+
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name,
+            for_lerp_partial,
+            for_try_lerpable,
+        }
+    }
+
+    // e.g. Shape::Circle { center: Vec2, radius: f32 }
+    fn from_named_enum(idents: EnumIdents) -> FieldTokensLerpable {
+        let variant_ident = idents.variant_ident();
+        let variant_str = variant_ident.to_string();
+
+        let fields = idents.data.fields.iter().collect::<Vec<_>>();
+        let field_names = fields
+            .iter()
+            .map(|x| x.ident.clone().unwrap())
+            .collect::<Vec<_>>();
+        let self_names = field_names
+            .iter()
+            .map(|x| format_ident!("self_{}", x))
+            .collect::<Vec<_>>();
+        let other_names = field_names
+            .iter()
+            .map(|x| format_ident!("other_{}", x))
+            .collect::<Vec<_>>();
+
+        let self_pattern = quote! { Self::#variant_ident { #(#field_names: #self_names,)* } };
+        let other_pattern = quote! { Self::#variant_ident { #(#field_names: #other_names,)* } };
+
+        let each_field =
+            |f: &dyn Fn(&LivecodeFieldReceiver, &str, &syn::Ident, &syn::Ident) -> TokenStream2| {
+                fields
+                    .iter()
+                    .zip(self_names.iter().zip(other_names.iter()))
+                    .map(|(field, (self_name, other_name))| {
+                        let field_str = field.ident.as_ref().unwrap().to_string();
+                        f(field, &field_str, self_name, other_name)
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+        let lerps = each_field(&|field, field_str, self_name, other_name| {
+            field.to_lerpify(field_str, quote! { #self_name }, quote! { #other_name })
+        });
+        let for_lerpable = quote! {
+            (#self_pattern, #other_pattern) => {
+                let pct = &lerpable::IsLerpingMethod::enter_field(pct, #variant_str);
+                Self::#variant_ident { #(#field_names: #lerps,)* }
+            }
+        };
+
+        let stateful_lerps = each_field(&|field, field_str, self_name, other_name| {
+            field.to_stateful_lerpify(field_str, quote! { #self_name }, quote! { #other_name })
+        });
+        let for_lerpable_mut = quote! {
+            (#self_pattern, #other_pattern) => {
+                lerpable::IsLerpingMethodMut::enter_field(method, #variant_str);
+                let result = Self::#variant_ident { #(#field_names: #stateful_lerps,)* };
+                lerpable::IsLerpingMethodMut::exit(method);
+                result
+            }
+        };
+
+        let field_plans = each_field(&|field, field_str, _, _| field.to_plan(field_str));
+        let for_plan = quote! {
+            lerpable::VariantLerpPlan { name: #variant_str, fields: vec![#(#field_plans,)*] }
+        };
+
+        let diffs = each_field(&|field, field_str, self_name, other_name| {
+            field.to_diff(
+                quote! { path.field(#variant_str).field(#field_str) },
+                quote! { #self_name },
+                quote! { #other_name },
+            )
+        });
+        let for_diff = quote! {
+            (#self_pattern, #other_pattern) => { #(#diffs)* }
+        };
+
+        let for_variant_name = quote! { Self::#variant_ident { .. } => #variant_str };
+
+        let partials =
+            each_field(&|field, _, self_name, _| field.to_lerp_partial(quote! { #self_name }));
+        let for_lerp_partial = quote! {
+            #self_pattern => Self::#variant_ident { #(#field_names: #partials,)* }
+        };
+
+        let try_lerps = each_field(&|field, field_str, self_name, other_name| {
+            field.to_try_lerpify(field_str, quote! { #self_name }, quote! { #other_name })
+        });
+        let for_try_lerpable = quote! {
+            (#self_pattern, #other_pattern) => {
+                let pct = &lerpable::IsLerpingMethod::enter_field(pct, #variant_str);
+                (|| -> Result<Self, lerpable::LerpError> {
+                    Ok(Self::#variant_ident { #(#field_names: #try_lerps,)* })
+                })()
+                .map_err(|e| e.in_field(#variant_str))?
+            }
+        };
+
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name,
+            for_lerp_partial,
+            for_try_lerpable,
+        }
     }
 
     // e.g. TileAxis::Diag
     fn from_unit_enum(idents: EnumIdents) -> FieldTokensLerpable {
         let variant_ident = idents.variant_ident();
-        let name = idents.enum_ident();
+        let variant_str = variant_ident.to_string();
 
         // hmm, not really needed, since it can fall back on th global step
         let for_lerpable: TokenStream2 = {
-            quote! { (#name::#variant_ident, #name::#variant_ident) => lerpable::step(self, other, pct) }
+            quote! { (Self::#variant_ident, Self::#variant_ident) => lerpable::step(self, other, pct) }
+        };
+
+        let for_lerpable_mut = quote! {
+            (Self::#variant_ident, Self::#variant_ident) => lerpable::step(self, other, &lerpable::IsLerpingMethodMut::next_leaf(method))
         };
 
-        FieldTokensLerpable { for_lerpable }
+        let for_plan = quote! {
+            lerpable::VariantLerpPlan { name: #variant_str, fields: vec![] }
+        };
+
+        let for_diff = quote! {
+            (Self::#variant_ident, Self::#variant_ident) => report.add(path.field(#variant_str), lerpable::LerpHow::Step, Some(false))
+        };
+
+        let for_variant_name = quote! { Self::#variant_ident => #variant_str };
+
+        let for_lerp_partial = quote! { Self::#variant_ident => self.clone() };
+
+        let for_try_lerpable = for_lerpable.clone();
+
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name,
+            for_lerp_partial,
+            for_try_lerpable,
+        }
     }
 
     // s: String
     fn from_noop_struct(idents: StructIdents) -> FieldTokensLerpable {
         let name = idents.name();
+        let name_str = name.to_string();
 
-        let method_def = idents.to_method_override();
+        let for_lerpable = {
+            let lerpify =
+                idents.to_lerpify(&name_str, quote! { &self.#name }, quote! { &other.#name });
+            quote! { #name: #lerpify }
+        };
 
-        let for_lerpable: TokenStream2 = quote! {
-            #name: {
-                #method_def;
-                lerpable::step(&self.#name, &other.#name, method)
-            }
+        let for_lerpable_mut = {
+            let lerpify_mut = idents.to_stateful_lerpify(
+                &name_str,
+                quote! { &self.#name },
+                quote! { &other.#name },
+            );
+            quote! { #name: #lerpify_mut }
+        };
+
+        let for_plan = idents.to_plan(&name_str);
+
+        let for_diff = idents.to_diff(
+            quote! { path.field(#name_str) },
+            quote! { &self.#name },
+            quote! { &other.#name },
+        );
+
+        let for_lerp_partial = {
+            let lerp_partial = idents.to_lerp_partial(quote! { &self.#name });
+            quote! { #name: #lerp_partial }
+        };
+
+        let for_try_lerpable = {
+            let try_lerpify =
+                idents.to_try_lerpify(&name_str, quote! { &self.#name }, quote! { &other.#name });
+            quote! { #name: #try_lerpify }
         };
 
-        FieldTokensLerpable { for_lerpable }
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name: quote! {},
+            for_lerp_partial,
+            for_try_lerpable,
+        }
     }
 
     // f32, Vec2, etc
     fn from_type_struct(idents: StructIdents) -> FieldTokensLerpable {
         let name = idents.name();
+        let name_str = name.to_string();
 
-        let method_def = idents.to_method_override();
+        // we'll just use the trait! (unless there's a func)
+        let for_lerpable = {
+            let lerpify =
+                idents.to_lerpify(&name_str, quote! { &self.#name }, quote! { &other.#name });
+            quote! { #name: #lerpify }
+        };
 
-        let lerpify = if let Some(func) = idents.func() {
-            quote! { #func(&self.#name, &other.#name, method) }
-        } else {
-            quote! { self.#name.lerpify(&other.#name, method) }
+        let for_lerpable_mut = {
+            let lerpify_mut = idents.to_stateful_lerpify(
+                &name_str,
+                quote! { &self.#name },
+                quote! { &other.#name },
+            );
+            quote! { #name: #lerpify_mut }
         };
 
-        // we'll just use the trait! (unless it's none, then we bail)
-        let for_lerpable = {
-            quote! { #name: {
-                #method_def
-                #lerpify
-            }}
+        let for_plan = idents.to_plan(&name_str);
+
+        let for_diff = idents.to_diff(
+            quote! { path.field(#name_str) },
+            quote! { &self.#name },
+            quote! { &other.#name },
+        );
+
+        let for_lerp_partial = {
+            let lerp_partial = idents.to_lerp_partial(quote! { &self.#name });
+            quote! { #name: #lerp_partial }
         };
 
-        FieldTokensLerpable { for_lerpable }
+        let for_try_lerpable = {
+            let try_lerpify =
+                idents.to_try_lerpify(&name_str, quote! { &self.#name }, quote! { &other.#name });
+            quote! { #name: #try_lerpify }
+        };
+
+        FieldTokensLerpable {
+            for_lerpable,
+            for_lerpable_mut,
+            for_plan,
+            for_diff,
+            for_variant_name: quote! {},
+            for_lerp_partial,
+            for_try_lerpable,
+        }
+    }
+}
+
+// defines `find` and `find_mut`, functions that get a shared field out of whichever variant
+fn shared_field_finder(
+    enum_name: &syn::Ident,
+    shared: &SharedField,
+    variant_count: usize,
+) -> TokenStream2 {
+    let field = &shared.name;
+    let ty = &shared.data.ty;
+    let variants = &shared.variants;
+    let pattern = quote! { #(#enum_name::#variants { #field, .. })|* };
+    let otherwise = if variants.len() < variant_count {
+        quote! { _ => None, }
+    } else {
+        quote! {}
+    };
+    quote! {
+        fn find(x: &#enum_name) -> Option<&#ty> {
+            match x {
+                #pattern => Some(#field),
+                #otherwise
+            }
+        }
+        #[allow(unused)]
+        fn find_mut(x: &mut #enum_name) -> Option<&mut #ty> {
+            match x {
+                #pattern => Some(#field),
+                #otherwise
+            }
+        }
+    }
+}
+
+// runs the container's post function, if there is one, on the result
+fn with_post(post: &Option<syn::Path>, value: TokenStream2) -> TokenStream2 {
+    match post {
+        Some(post) => quote! { #post(#value) },
+        None => value,
+    }
+}
+
+// if there's a post function, the default lerp_partial (a clone) needs to go through it too
+fn post_lerp_partial(post: &Option<syn::Path>) -> TokenStream2 {
+    match post {
+        Some(post) => quote! {
+            fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, _pct: __LerpT) -> Self {
+                #post(std::clone::Clone::clone(self))
+            }
+        },
+        None => quote! {},
+    }
+}
+
+// with fallible, implements TryLerpable, with `value` evaluating to Self and using `?` for
+// errors. an empty `value` uses the default, which can't fail.
+fn try_lerpable_impl(name: &syn::Ident, fallible: bool, value: TokenStream2) -> TokenStream2 {
+    if !fallible {
+        quote! {}
+    } else if value.is_empty() {
+        quote! { impl lerpable::TryLerpable for #name {} }
+    } else {
+        quote! {
+            impl lerpable::TryLerpable for #name {
+                #[allow(unused_variables)]
+                fn try_lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Result<Self, lerpable::LerpError> {
+                    Ok(#value)
+                }
+            }
+        }
+    }
+}
+
+// what the struct's impl is for. with remote, we can't implement Lerpable for the foreign
+// type, so it's a local trait with the same methods instead
+fn lerpable_target(name: &syn::Ident, remote: &Option<syn::Path>) -> (TokenStream2, TokenStream2) {
+    match remote {
+        Some(remote) => (quote! { LerpableRemote }, quote! { #remote }),
+        None => (quote! { lerpable::Lerpable }, quote! { #name }),
+    }
+}
+
+// with remote, declares the local trait and exposes the impl as functions on the mirror
+// struct, which can be used with `func`
+fn with_remote(
+    name: &syn::Ident,
+    remote: &Option<syn::Path>,
+    lerpable_impl: TokenStream2,
+) -> TokenStream2 {
+    let Some(remote) = remote else {
+        return lerpable_impl;
+    };
+
+    quote! {
+        const _: () = {
+            trait LerpableRemote: Sized {
+                fn lerpify<__LerpT: lerpable::IsLerpingMethod>(&self, other: &Self, pct: &__LerpT) -> Self;
+                fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(&self, other: &Self, method: &mut __LerpM) -> Self;
+                fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(&self, pct: __LerpT) -> Self;
+                fn lerp_plan() -> lerpable::LerpPlan;
+                fn lerp_diff(&self, other: &Self, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport);
+            }
+
+            #lerpable_impl
+
+            impl #name {
+                pub fn lerpify<__LerpT: lerpable::IsLerpingMethod>(this: &#remote, other: &#remote, pct: &__LerpT) -> #remote {
+                    LerpableRemote::lerpify(this, other, pct)
+                }
+
+                pub fn lerpify_mut<__LerpM: lerpable::IsLerpingMethodMut>(this: &#remote, other: &#remote, method: &mut __LerpM) -> #remote {
+                    LerpableRemote::lerpify_mut(this, other, method)
+                }
+
+                pub fn lerp_partial<__LerpT: lerpable::IsLerpingMethod>(this: &#remote, pct: __LerpT) -> #remote {
+                    LerpableRemote::lerp_partial(this, pct)
+                }
+
+                pub fn lerp_plan() -> lerpable::LerpPlan {
+                    <#remote as LerpableRemote>::lerp_plan()
+                }
+
+                pub fn lerp_diff(this: &#remote, other: &#remote, path: &lerpable::FieldPath, report: &mut lerpable::LerpReport) {
+                    LerpableRemote::lerp_diff(this, other, path, report)
+                }
+            }
+        };
     }
 }
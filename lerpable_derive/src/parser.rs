@@ -1,10 +1,81 @@
-use darling::{ast, FromDeriveInput, FromField, FromVariant};
+use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, ToTokens};
 
 #[derive(Debug)]
 pub(crate) struct ParsedFieldIdent {
     pub(crate) name: syn::Ident,
+    pub(crate) fade: bool,                // enums only
+    pub(crate) cross: Option<String>,     // enums only
+    pub(crate) shared: Vec<SharedField>,  // enums only
+    pub(crate) variant_count: usize,      // enums only
+    pub(crate) post: Option<syn::Path>,   // fn(Self) -> Self, run on each result
+    pub(crate) remote: Option<syn::Path>, // structs only, the foreign type this mirrors
+    pub(crate) fallible: bool,            // also implement TryLerpable
+}
+
+// a field that's in more than one struct variant of an enum, with the same type, so it
+// can keep lerping while the variant steps
+#[derive(Debug)]
+pub(crate) struct SharedField {
+    pub(crate) name: syn::Ident,
+    pub(crate) data: LivecodeFieldReceiver, // the first one, for the attributes
+    pub(crate) variants: Vec<syn::Ident>,
+}
+
+impl SharedField {
+    // fields tagged with #[lerpable(shared)], or every candidate if `all` is set
+    fn find(variants: &[LivecodeVariantReceiver], all: bool) -> Vec<SharedField> {
+        let mut shared: Vec<(SharedField, String, bool)> = vec![];
+        for variant in variants {
+            if variant.fields.style != ast::Style::Struct {
+                continue;
+            }
+            for field in variant.fields.iter() {
+                let name = field.ident.clone().unwrap();
+                let ty = field.ty.to_token_stream().to_string();
+                match shared.iter_mut().find(|(x, _, _)| x.name == name) {
+                    Some((x, _, tagged)) => {
+                        x.variants.push(variant.ident.clone());
+                        *tagged |= field.shared;
+                    }
+                    None => shared.push((
+                        SharedField {
+                            name: name.clone(),
+                            data: field.clone(),
+                            variants: vec![variant.ident.clone()],
+                        },
+                        ty,
+                        field.shared,
+                    )),
+                }
+            }
+        }
+
+        shared
+            .into_iter()
+            .filter(|(x, ty, tagged)| {
+                if !(all || *tagged) || x.variants.len() < 2 {
+                    return false;
+                }
+                let same_type = variants.iter().all(|v| {
+                    v.fields.style != ast::Style::Struct
+                        || v.fields.iter().all(|f| {
+                            f.ident.as_ref() != Some(&x.name)
+                                || f.ty.to_token_stream().to_string() == *ty
+                        })
+                });
+                if !same_type && *tagged {
+                    panic!(
+                        "shared field {} has different types in different variants",
+                        x.name
+                    );
+                }
+                same_type
+            })
+            .map(|(x, _, _)| x)
+            .collect()
+    }
 }
 
 // trait and helpers needed to parse a variety of objects
@@ -14,24 +85,95 @@ where
 {
     fn from_newtype_struct(_idents: StructIdents, parent_ident: syn::Ident) -> Self;
     fn from_unnamed_enum(idents: EnumIdents) -> Self;
+    fn from_named_enum(idents: EnumIdents) -> Self;
     fn from_unit_enum(idents: EnumIdents) -> Self;
     fn from_noop_struct(idents: StructIdents) -> Self;
     fn from_type_struct(idents: StructIdents) -> Self;
 
     fn from_ast(ast_receiver: LivecodeReceiver) -> TokenStream2 {
-        match ast_receiver.data {
-            ast::Data::Enum(_) => Self::make_enum(&ast_receiver),
-            ast::Data::Struct(ast::Fields {
-                style: ast::Style::Tuple,
-                ..
-            }) => Self::make_newtype(&ast_receiver),
-            ast::Data::Struct(_) => Self::make_struct(&ast_receiver),
+        let ast_receiver = ast_receiver.with_container_method();
+
+        let impls = if ast_receiver.skip {
+            Self::make_skip(&ast_receiver)
+        } else if let Some(via) = ViaIdents::from_receiver(&ast_receiver) {
+            Self::make_via(&ast_receiver, via)
+        } else {
+            match ast_receiver.data {
+                ast::Data::Enum(_) => Self::make_enum(&ast_receiver),
+                ast::Data::Struct(ast::Fields {
+                    style: ast::Style::Tuple,
+                    ..
+                }) => Self::make_newtype(&ast_receiver),
+                ast::Data::Struct(_) => Self::make_struct(&ast_receiver),
+            }
+        };
+
+        // so `lerpable::` points at the re-exported crate
+        match ast_receiver.crate_path() {
+            Some(crate_path) => quote! {
+                const _: () = {
+                    use #crate_path as lerpable;
+                    #impls
+                };
+            },
+            None => impls,
         }
     }
 
     fn make_enum_final(idents: ParsedFieldIdent, variants: Vec<Self>) -> TokenStream2;
     fn make_struct_final(idents: ParsedFieldIdent, variants: Vec<Self>) -> TokenStream2;
     fn make_newtype_struct_final(idents: ParsedFieldIdent, variants: Vec<Self>) -> TokenStream2;
+    fn make_ordinal_enum_final(idents: ParsedFieldIdent, variants: Vec<syn::Ident>)
+        -> TokenStream2;
+    fn make_via_final(idents: ParsedFieldIdent, via: ViaIdents) -> TokenStream2;
+    fn make_skip_final(idents: ParsedFieldIdent) -> TokenStream2;
+
+    fn make_skip(s: &LivecodeReceiver) -> TokenStream2 {
+        if s.fade
+            || s.cross.is_some()
+            || s.shared_fields
+            || s.ordinal
+            || s.via.is_some()
+            || s.post.is_some()
+            || s.remote.is_some()
+        {
+            panic!(
+                "skip steps the whole type, so it can't be used with other container attributes"
+            );
+        }
+
+        let idents = ParsedFieldIdent {
+            name: s.ident.clone(),
+            fade: false,
+            cross: None,
+            shared: vec![],
+            variant_count: 0,
+            post: None,
+            remote: None,
+            fallible: s.fallible,
+        };
+
+        Self::make_skip_final(idents)
+    }
+
+    fn make_via(s: &LivecodeReceiver, via: ViaIdents) -> TokenStream2 {
+        if s.fade || s.cross.is_some() || s.shared_fields || s.ordinal || s.remote.is_some() {
+            panic!("via can't be used with fade, cross, shared_fields, ordinal, or remote");
+        }
+
+        let idents = ParsedFieldIdent {
+            name: s.ident.clone(),
+            fade: false,
+            cross: None,
+            shared: vec![],
+            variant_count: 0,
+            post: s.post_path(),
+            remote: None,
+            fallible: s.fallible,
+        };
+
+        Self::make_via_final(idents, via)
+    }
 
     fn make_struct(s: &LivecodeReceiver) -> TokenStream2 {
         let name = s.ident.clone();
@@ -66,7 +208,24 @@ where
             })
             .collect::<Vec<_>>();
 
-        let idents = ParsedFieldIdent { name: name.clone() };
+        if s.fade || s.cross.is_some() || s.shared_fields || s.ordinal {
+            panic!("fade, cross, shared_fields, and ordinal only work on enums");
+        }
+
+        if s.fallible && s.remote.is_some() {
+            panic!("fallible can't be used with remote");
+        }
+
+        let idents = ParsedFieldIdent {
+            name: name.clone(),
+            fade: false,
+            cross: None,
+            shared: vec![],
+            variant_count: 0,
+            post: s.post_path(),
+            remote: s.remote_path(),
+            fallible: s.fallible,
+        };
 
         Self::make_struct_final(idents, livecodable_fields)
     }
@@ -79,24 +238,70 @@ where
 
         let variants = e.data.clone().take_enum().unwrap();
 
+        if e.remote.is_some() {
+            panic!("remote only works on structs");
+        }
+
+        if e.ordinal {
+            if e.fade || e.cross.is_some() || e.shared_fields {
+                panic!("ordinal can't be used with fade, cross, or shared_fields");
+            }
+            let unit_variants = variants
+                .iter()
+                .map(|variant| match variant.fields.style {
+                    ast::Style::Unit => variant.ident.clone(),
+                    _ => panic!("ordinal only works on enums where every variant is a unit"),
+                })
+                .collect::<Vec<_>>();
+            let idents = ParsedFieldIdent {
+                name: name.clone(),
+                fade: false,
+                cross: None,
+                shared: vec![],
+                variant_count: unit_variants.len(),
+                post: e.post_path(),
+                remote: None,
+                fallible: e.fallible,
+            };
+            return Self::make_ordinal_enum_final(idents, unit_variants);
+        }
+
         // just go through and find ones that wrap around a type, and make sure those types are
         let variants = variants
             .iter()
             .map(|variant| {
                 let ident = EnumIdents {
-                    enum_name: name.clone(),
                     data: variant.clone(),
                 };
 
                 match variant.fields.style {
                     ast::Style::Tuple => Self::from_unnamed_enum(ident),
-                    ast::Style::Struct => panic!("enum named fields not supported yet"),
+                    ast::Style::Struct => Self::from_named_enum(ident),
                     ast::Style::Unit => Self::from_unit_enum(ident),
                 }
             })
             .collect::<Vec<_>>();
 
-        let idents = ParsedFieldIdent { name: name.clone() };
+        if e.fade && e.cross.is_some() {
+            panic!("use either fade or cross, not both");
+        }
+
+        let enum_variants = e.data.clone().take_enum().unwrap();
+        let shared = SharedField::find(&enum_variants, e.shared_fields);
+        if !shared.is_empty() && (e.fade || e.cross.is_some()) {
+            panic!("shared fields only apply when stepping, not with fade or cross");
+        }
+
+        let idents = ParsedFieldIdent {
+            name: name.clone(),
+            fade: e.fade,
+            cross: e.cross.clone(),
+            shared,
+            variant_count: enum_variants.len(),
+            post: e.post_path(),
+            remote: None,
+            fallible: e.fallible,
+        };
 
         Self::make_enum_final(idents, variants)
     }
@@ -132,7 +337,24 @@ where
             })
             .collect::<Vec<_>>();
 
-        let idents = ParsedFieldIdent { name: name.clone() };
+        if s.fade || s.cross.is_some() || s.shared_fields || s.ordinal {
+            panic!("fade, cross, shared_fields, and ordinal only work on enums");
+        }
+
+        if s.fallible && s.remote.is_some() {
+            panic!("fallible can't be used with remote");
+        }
+
+        let idents = ParsedFieldIdent {
+            name: name.clone(),
+            fade: false,
+            cross: None,
+            shared: vec![],
+            variant_count: 0,
+            post: s.post_path(),
+            remote: s.remote_path(),
+            fallible: s.fallible,
+        };
 
         Self::make_newtype_struct_final(idents, livecodable_fields)
     }
@@ -142,12 +364,69 @@ where
 #[darling(attributes(lerpable))]
 pub(crate) struct LivecodeFieldReceiver {
     pub(crate) ident: Option<syn::Ident>,
+    pub(crate) ty: syn::Type,
     pub(crate) method: Option<String>, // from this point on, start using this method instead of the function we started with
     pub(crate) func: Option<String>, // if you need to use types from other packages, you could use a func to wrap simple types
+    pub(crate) with: Option<String>, // like func, but a module with both `lerpify` and `lerp_partial`
+    pub(crate) step_at: Option<f64>, // switch non-numbers at this pct instead of 0.5
+    pub(crate) keep: Option<String>, // "start" or "end", always take that side
+    pub(crate) round: Option<String>, // how integers round: "nearest", "floor", "ceil", or "stochastic"
+    pub(crate) overflow: Option<String>, // integers outside their type: "saturate", "wrap", "clamp", or "checked"
+    #[darling(default)]
+    pub(crate) log: bool, // space floats evenly in log space, for scales and frequencies
+    #[darling(default)]
+    pub(crate) shared: bool, // on enum struct variants, keep lerping this field when the variant changes
+    pub(crate) clamp: Option<ClampReceiver>, // keep the lerped value within these bounds
+    pub(crate) angle: Option<AngleReceiver>, // lerp around the circle, for f32 or f64 angles
+}
+
+// #[lerpable(clamp(min = 0.0, max = 1.0))], either can be left off
+#[derive(Debug, FromMeta, Clone)]
+pub(crate) struct ClampReceiver {
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+}
+// #[lerpable(angle)] or #[lerpable(angle(direction = "cw", turns = 1, degrees))]
+#[derive(Debug, Default, FromMeta, Clone)]
+#[darling(default, from_word = || Ok(Default::default()))]
+pub(crate) struct AngleReceiver {
+    direction: Option<String>, // "shortest" (the default), "cw", or "ccw"
+    turns: u32,                // extra full turns
+    degrees: bool,             // instead of radians
 }
+
+impl AngleReceiver {
+    // the lerpable::AnglePath expression
+    fn to_path(&self) -> TokenStream2 {
+        let path = if self.degrees {
+            quote! { lerpable::AnglePath::degrees() }
+        } else {
+            quote! { lerpable::AnglePath::radians() }
+        };
+        let direction = match self.direction.as_deref() {
+            None | Some("shortest") => quote! { lerpable::AngleDirection::Shortest },
+            Some("cw") => quote! { lerpable::AngleDirection::Clockwise },
+            Some("ccw") => quote! { lerpable::AngleDirection::CounterClockwise },
+            Some(direction) => panic!(
+                "angle direction should be \"shortest\", \"cw\", or \"ccw\", not {}",
+                direction
+            ),
+        };
+        let turns = self.turns;
+        quote! { #path.with_direction(#direction).with_turns(#turns) }
+    }
+
+    // for plans and reports
+    fn label(&self) -> String {
+        let unit = if self.degrees { "degrees" } else { "radians" };
+        let direction = self.direction.as_deref().unwrap_or("shortest");
+        format!("angle({}, {}, {} turns)", unit, direction, self.turns)
+    }
+}
+
 impl LivecodeFieldReceiver {
     fn is_skip(&self) -> bool {
-        self.method.as_deref().map_or(false, |x| x == "skip")
+        self.method.as_deref() == Some("skip")
     }
 
     fn how_to_control_this(&self) -> HowToControlThis {
@@ -159,7 +438,7 @@ impl LivecodeFieldReceiver {
     }
 
     fn to_method_override(&self) -> TokenStream2 {
-        if self.is_skip() {
+        let method_def = if self.is_skip() {
             quote! {
                 let method = pct;
             }
@@ -167,14 +446,405 @@ impl LivecodeFieldReceiver {
             let method: syn::Path =
                 syn::parse_str(method_str).expect("Custom method is invalid path!");
             quote! {
-                let method = &#method().with_lerp_pct(pct.lerp_pct());
+                let method = &lerpable::IsLerpingMethod::with_lerp_pct(&#method(), lerpable::IsLerpingMethod::lerp_pct(pct));
             }
         } else {
             quote! {
                 let method = pct;
             }
+        };
+        match self.method_wrapper(quote! { method.clone() }) {
+            Some(wrapped) => quote! {
+                #method_def
+                let method = &#wrapped;
+            },
+            None => method_def,
         }
     }
+
+    // wraps `method` for round, overflow, and log, and then step_at or keep
+    fn method_wrapper(&self, method: TokenStream2) -> Option<TokenStream2> {
+        let method = match self.round.as_deref() {
+            None => method,
+            Some("nearest") => quote! { lerpable::Round::nearest(#method) },
+            Some("floor") => quote! { lerpable::Round::floor(#method) },
+            Some("ceil") => quote! { lerpable::Round::ceil(#method) },
+            Some("stochastic") => {
+                // the draws depend on the field and the pct, so they're repeatable
+                let name = self
+                    .ident
+                    .as_ref()
+                    .map_or("0".to_owned(), |x| x.to_string());
+                let seed = fnv1a(&name);
+                quote! {
+                    {
+                        let method = #method;
+                        let seed = #seed ^ lerpable::IsLerpingMethod::lerp_pct(&method).to_bits();
+                        lerpable::Round::stochastic(method, seed)
+                    }
+                }
+            }
+            Some(round) => panic!(
+                "round should be \"nearest\", \"floor\", \"ceil\", or \"stochastic\", not {}",
+                round
+            ),
+        };
+
+        let method = match self.overflow.as_deref() {
+            None => method,
+            Some("saturate") => {
+                quote! { lerpable::OnOverflow::new(#method, lerpable::Overflow::Saturate) }
+            }
+            Some("wrap") => quote! { lerpable::OnOverflow::new(#method, lerpable::Overflow::Wrap) },
+            Some("clamp") => {
+                quote! { lerpable::OnOverflow::new(#method, lerpable::Overflow::Clamp) }
+            }
+            Some("checked") => {
+                quote! { lerpable::OnOverflow::new(#method, lerpable::Overflow::Checked) }
+            }
+            Some(overflow) => panic!(
+                "overflow should be \"saturate\", \"wrap\", \"clamp\", or \"checked\", not {}",
+                overflow
+            ),
+        };
+
+        let method = if self.log {
+            quote! { lerpable::Geometric::new(#method) }
+        } else {
+            method
+        };
+        let wrapped = self.round.is_some() || self.overflow.is_some() || self.log;
+
+        match (self.step_at, self.keep.as_deref()) {
+            (Some(_), Some(_)) => panic!("use either step_at or keep, not both"),
+            (Some(at), None) => Some(quote! { lerpable::StepAt::new(#method, #at) }),
+            (None, Some("start")) => Some(quote! { lerpable::Keep::start(#method) }),
+            (None, Some("end")) => Some(quote! { lerpable::Keep::end(#method) }),
+            (None, Some(keep)) => panic!("keep should be \"start\" or \"end\", not {}", keep),
+            (None, None) if wrapped => Some(method),
+            (None, None) => None,
+        }
+    }
+
+    // the lerpify expression for this field, with `pct` in scope
+    pub(crate) fn to_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.lerpify_inner(name, this, other, false)
+    }
+
+    // the same, but with try_lerpify, and `?` to return any error with this field in its path.
+    // skip and func fields can't fail. with and angle fields don't go through try_lerpify, so
+    // their result gets checked instead.
+    pub(crate) fn to_try_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.lerpify_inner(name, this, other, true)
+    }
+
+    fn lerpify_inner(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+        fallible: bool,
+    ) -> TokenStream2 {
+        let method_def = self.to_method_override();
+        let lerpify = if self.is_skip() {
+            quote! { lerpable::step(#this, #other, method) }
+        } else if let Some(call) = self.func_call(&this, &other, quote! { method }) {
+            if fallible && self.func.is_none() {
+                quote! { lerpable::try_check(#call).map_err(|e| e.in_field(#name))? }
+            } else {
+                call
+            }
+        } else if fallible {
+            quote! {
+                lerpable::TryLerpable::try_lerpify(#this, #other, method)
+                    .map_err(|e| e.in_field(#name))?
+            }
+        } else {
+            quote! { lerpable::Lerpable::lerpify(#this, #other, method) }
+        };
+        let lerpify = self.clamped(lerpify);
+        quote! {
+            {
+                let pct = &lerpable::IsLerpingMethod::enter_field(pct, #name);
+                #method_def
+                #lerpify
+            }
+        }
+    }
+
+    // wraps the expression for this field's new value if it has a clamp
+    pub(crate) fn clamped(&self, value: TokenStream2) -> TokenStream2 {
+        match &self.clamp {
+            Some(ClampReceiver { min, max }) => {
+                let min = min
+                    .as_ref()
+                    .map_or(quote! { None }, |x| quote! { Some(#x) });
+                let max = max
+                    .as_ref()
+                    .map_or(quote! { None }, |x| quote! { Some(#x) });
+                quote! { lerpable::clamp(#value, #min, #max) }
+            }
+            None => value,
+        }
+    }
+
+    // the lerp_partial expression for this field, with `pct` in scope
+    pub(crate) fn to_lerp_partial(&self, this: TokenStream2) -> TokenStream2 {
+        self.clamped(if let Some(with) = self.with_path() {
+            quote! { #with::lerp_partial(#this, pct.clone()) }
+        } else if self.is_skip() || self.func.is_some() {
+            quote! { std::clone::Clone::clone(#this) }
+        } else {
+            quote! { lerpable::Lerpable::lerp_partial(#this, pct.clone()) }
+        })
+    }
+
+    // the lerpify_mut expression for this field, with `method: &mut M` in scope
+    pub(crate) fn to_stateful_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        let lerpify_mut = self.clamped(self.stateful_lerpify_inner(this, other));
+        quote! {
+            {
+                lerpable::IsLerpingMethodMut::enter_field(method, #name);
+                let result = #lerpify_mut;
+                lerpable::IsLerpingMethodMut::exit(method);
+                result
+            }
+        }
+    }
+
+    fn stateful_lerpify_inner(&self, this: TokenStream2, other: TokenStream2) -> TokenStream2 {
+        let next_leaf = quote! { lerpable::IsLerpingMethodMut::next_leaf(method) };
+        let wrapped_leaf = self.method_wrapper(next_leaf.clone());
+        if self.is_skip() {
+            let leaf = wrapped_leaf.unwrap_or(next_leaf);
+            return quote! { lerpable::step(#this, #other, &#leaf) };
+        }
+
+        // a custom method or func doesn't know about the stateful method, so hand it a leaf
+        let leaf_method = if let Some(method_str) = self.custom_method_str() {
+            let custom: syn::Path =
+                syn::parse_str(method_str).expect("Custom method is invalid path!");
+            let leaf = quote! { lerpable::IsLerpingMethod::with_lerp_pct(&#custom(), lerpable::IsLerpingMethod::lerp_pct(&#next_leaf)) };
+            self.method_wrapper(leaf.clone()).unwrap_or(leaf)
+        } else {
+            wrapped_leaf.clone().unwrap_or(next_leaf)
+        };
+
+        if let Some(call) = self.func_call(&this, &other, quote! { &#leaf_method }) {
+            call
+        } else if self.custom_method_str().is_some() || wrapped_leaf.is_some() {
+            quote! { lerpable::Lerpable::lerpify(#this, #other, &#leaf_method) }
+        } else {
+            quote! { lerpable::Lerpable::lerpify_mut(#this, #other, method) }
+        }
+    }
+
+    // the func that lerps this field, which is `lerpify` in the module for `with`
+    fn func_str(&self) -> Option<String> {
+        match (&self.func, &self.with) {
+            (Some(_), Some(_)) => panic!("use either func or with, not both"),
+            (Some(func), None) => Some(func.clone()),
+            (None, Some(with)) => Some(format!("{}::lerpify", with)),
+            (None, None) => None,
+        }
+    }
+
+    fn with_path(&self) -> Option<syn::Path> {
+        self.with.as_ref().map(|x| {
+            syn::parse_str(x).unwrap_or_else(|_| panic!("Custom with {} is invalid path!", x))
+        })
+    }
+
+    fn func_path(&self) -> Option<syn::Path> {
+        if let Some(func_str) = &self.func_str() {
+            let method: syn::Path = syn::parse_str(func_str)
+                .unwrap_or_else(|_| panic!("Custom func {} is invalid path!", func_str));
+            Some(method)
+        } else {
+            None
+        }
+    }
+
+    // calls func, or the lerp for angle, which don't go through Lerpable
+    fn func_call(
+        &self,
+        this: &TokenStream2,
+        other: &TokenStream2,
+        method: TokenStream2,
+    ) -> Option<TokenStream2> {
+        match (&self.angle, self.func_path()) {
+            (Some(_), Some(_)) => panic!("use either angle or func/with, not both"),
+            (Some(angle), None) => {
+                let path = angle.to_path();
+                Some(quote! { lerpable::lerp_angle(#this, #other, #method, #path) })
+            }
+            (None, Some(func)) => Some(quote! { #func(#this, #other, #method) }),
+            (None, None) => None,
+        }
+    }
+
+    // what plans and reports show for func_call
+    fn func_label(&self) -> Option<String> {
+        self.angle
+            .as_ref()
+            .map(|x| x.label())
+            .or_else(|| self.func_str())
+    }
+
+    fn custom_method_str(&self) -> Option<&str> {
+        self.method.as_deref().filter(|x| *x != "skip")
+    }
+
+    // lerpable::FieldAttrs for the attributes that change how this field lerps. bad values
+    // are left out here, method_wrapper complains about them.
+    fn to_attrs(&self) -> Vec<TokenStream2> {
+        let mut attrs = vec![];
+        match self.keep.as_deref() {
+            Some("start") => {
+                attrs.push(quote! { lerpable::FieldAttr::Keep(lerpable::KeepSide::Start) })
+            }
+            Some("end") => {
+                attrs.push(quote! { lerpable::FieldAttr::Keep(lerpable::KeepSide::End) })
+            }
+            _ => {}
+        }
+        if let Some(at) = self.step_at {
+            attrs.push(quote! { lerpable::FieldAttr::StepAt(#at) });
+        }
+        let round = match self.round.as_deref() {
+            Some("nearest") => Some(quote! { Nearest }),
+            Some("floor") => Some(quote! { Floor }),
+            Some("ceil") => Some(quote! { Ceil }),
+            Some("stochastic") => Some(quote! { Stochastic }),
+            _ => None,
+        };
+        if let Some(mode) = round {
+            attrs.push(quote! { lerpable::FieldAttr::Round(lerpable::RoundingMode::#mode) });
+        }
+        let overflow = match self.overflow.as_deref() {
+            Some("saturate") => Some(quote! { Saturate }),
+            Some("wrap") => Some(quote! { Wrap }),
+            Some("clamp") => Some(quote! { Clamp }),
+            Some("checked") => Some(quote! { Checked }),
+            _ => None,
+        };
+        if let Some(policy) = overflow {
+            attrs.push(quote! { lerpable::FieldAttr::Overflow(lerpable::Overflow::#policy) });
+        }
+        if self.log {
+            attrs.push(quote! { lerpable::FieldAttr::Log });
+        }
+        if let Some(ClampReceiver { min, max }) = &self.clamp {
+            let min = min.as_ref().map(|x| quote!(#x).to_string());
+            let max = max.as_ref().map(|x| quote!(#x).to_string());
+            let min = option_str_tokens(min.as_deref());
+            let max = option_str_tokens(max.as_deref());
+            attrs.push(quote! { lerpable::FieldAttr::Clamp { min: #min, max: #max } });
+        }
+        attrs
+    }
+
+    // the lerpable::FieldLerpPlan describing this field, `name` is the field name or "0"
+    pub(crate) fn to_plan(&self, name: &str) -> TokenStream2 {
+        let control = match self.how_to_control_this() {
+            HowToControlThis::Skip => quote! { lerpable::HowToControlThis::Skip },
+            HowToControlThis::LerpifyType => quote! { lerpable::HowToControlThis::LerpifyType },
+        };
+        let method = option_str_tokens(self.custom_method_str());
+        let func = option_str_tokens(self.func_label().as_deref());
+        let plan = if self.is_skip() || self.func_label().is_some() {
+            quote! { None }
+        } else {
+            let ty = &self.ty;
+            quote! { Some(<#ty as lerpable::Lerpable>::lerp_plan) }
+        };
+        let attrs = self.to_attrs();
+
+        quote! {
+            lerpable::FieldLerpPlan {
+                name: #name,
+                control: #control,
+                method: #method,
+                func: #func,
+                attrs: vec![#(#attrs),*],
+                plan: #plan,
+            }
+        }
+    }
+
+    // adds this field's entries to `report`, `this` and `other` are references to the field
+    pub(crate) fn to_diff(
+        &self,
+        path: TokenStream2,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        let diff = self.to_diff_inner(path, this, other);
+        let attrs = self.to_attrs();
+        if attrs.is_empty() {
+            diff
+        } else {
+            quote! {
+                {
+                    let start = report.len();
+                    #diff
+                    report.add_attrs_since(start, &[#(#attrs),*]);
+                }
+            }
+        }
+    }
+
+    fn to_diff_inner(
+        &self,
+        path: TokenStream2,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        if self.is_skip() {
+            quote! { report.add(#path, lerpable::LerpHow::Step, None); }
+        } else if let Some(func) = self.func_label() {
+            quote! { report.add(#path, lerpable::LerpHow::Func(#func), None); }
+        } else if let Some(method) = self.custom_method_str() {
+            quote! {
+                {
+                    let start = report.len();
+                    lerpable::Lerpable::lerp_diff(#this, #other, &#path, report);
+                    report.set_method_since(start, #method);
+                }
+            }
+        } else {
+            quote! { lerpable::Lerpable::lerp_diff(#this, #other, &#path, report); }
+        }
+    }
+}
+
+// seeds for stochastic rounding, so they're the same from build to build
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn option_str_tokens(s: Option<&str>) -> TokenStream2 {
+    match s {
+        Some(s) => quote! { Some(#s) },
+        None => quote! { None },
+    }
 }
 
 // for enums
@@ -190,12 +860,100 @@ pub(crate) struct LivecodeVariantReceiver {
 pub(crate) struct LivecodeReceiver {
     ident: syn::Ident,
     data: ast::Data<LivecodeVariantReceiver, LivecodeFieldReceiver>,
+    #[darling(default)]
+    fade: bool, // when variants change, fade the old one out and the new one in using lerp_partial
+    cross: Option<String>, // when variants change, call this function instead of stepping
+    #[darling(default)]
+    shared_fields: bool, // when variants change, keep lerping fields with the same name and type
+    #[darling(default)]
+    ordinal: bool, // for unit-only enums, step through the variants in between
+    via: Option<String>,   // convert to this type, lerp that, and convert back
+    to_via: Option<String>, // fn(&Self) -> Via, if you don't want to use Into
+    from_via: Option<String>, // fn(Via) -> Self, if you don't want to use From
+    post: Option<String>,  // fn(Self) -> Self, to fix up the result of lerpify and lerp_partial
+    remote: Option<String>, // this struct mirrors a foreign type, so generate functions for that instead
+    method: Option<String>, // the method for every field that doesn't set its own
+    #[darling(default)]
+    skip: bool, // step the whole thing, instead of going into the fields
+    #[darling(default)]
+    fallible: bool, // also implement TryLerpable, which returns errors instead of stepping
+    #[darling(rename = "crate")]
+    crate_path: Option<String>, // where to find lerpable, if it's re-exported from somewhere else
+}
+
+// for #[lerpable(via = "Repr")]
+pub(crate) struct ViaIdents {
+    pub(crate) ty: syn::Type,
+    pub(crate) to_via: Option<syn::Path>,
+    pub(crate) from_via: Option<syn::Path>,
+}
+
+impl ViaIdents {
+    fn from_receiver(s: &LivecodeReceiver) -> Option<Self> {
+        let parse_path = |x: &Option<String>| {
+            x.as_ref().map(|x| {
+                syn::parse_str::<syn::Path>(x)
+                    .unwrap_or_else(|_| panic!("Custom via function {} is invalid path!", x))
+            })
+        };
+
+        let via = s.via.as_ref()?;
+        let ty = syn::parse_str(via).unwrap_or_else(|_| panic!("via {} is invalid type!", via));
+        Some(ViaIdents {
+            ty,
+            to_via: parse_path(&s.to_via),
+            from_via: parse_path(&s.from_via),
+        })
+    }
+}
+
+impl LivecodeReceiver {
+    fn crate_path(&self) -> Option<syn::Path> {
+        self.crate_path
+            .as_ref()
+            .map(|x| syn::parse_str(x).unwrap_or_else(|_| panic!("crate {} is invalid path!", x)))
+    }
+
+    // hands the container's method down to the fields that don't have one
+    fn with_container_method(self) -> Self {
+        let Some(method) = self.method.clone() else {
+            return self;
+        };
+        let set_method = |mut field: LivecodeFieldReceiver| {
+            field.method.get_or_insert_with(|| method.clone());
+            field
+        };
+
+        let data = match self.data {
+            ast::Data::Enum(variants) => ast::Data::Enum(
+                variants
+                    .into_iter()
+                    .map(|mut variant| {
+                        variant.fields = variant.fields.map(set_method);
+                        variant
+                    })
+                    .collect(),
+            ),
+            ast::Data::Struct(fields) => ast::Data::Struct(fields.map(set_method)),
+        };
+        Self { data, ..self }
+    }
+
+    fn remote_path(&self) -> Option<syn::Path> {
+        self.remote
+            .as_ref()
+            .map(|x| syn::parse_str(x).unwrap_or_else(|_| panic!("remote {} is invalid path!", x)))
+    }
+
+    fn post_path(&self) -> Option<syn::Path> {
+        self.post.as_ref().map(|x| {
+            syn::parse_str(x).unwrap_or_else(|_| panic!("Custom post {} is invalid path!", x))
+        })
+    }
 }
-impl LivecodeReceiver {}
 
 // represents an enum
 pub(crate) struct EnumIdents {
-    pub(crate) enum_name: syn::Ident,
     pub(crate) data: LivecodeVariantReceiver,
 }
 
@@ -204,18 +962,9 @@ impl EnumIdents {
         self.data.ident.clone()
     }
 
-    pub(crate) fn enum_ident(&self) -> syn::Ident {
-        self.enum_name.clone()
-    }
-
-    pub(crate) fn how_to_control_internal(&self) -> HowToControlThis {
+    pub(crate) fn internal(&self) -> &LivecodeFieldReceiver {
         // there should be just one field!
-        self.data
-            .fields
-            .fields
-            .first()
-            .unwrap()
-            .how_to_control_this()
+        self.data.fields.fields.first().unwrap()
     }
 }
 
@@ -228,18 +977,48 @@ impl StructIdents {
         self.data.ident.clone().unwrap()
     }
 
-    pub(crate) fn to_method_override(&self) -> TokenStream2 {
-        self.data.to_method_override()
+    pub(crate) fn to_plan(&self, name: &str) -> TokenStream2 {
+        self.data.to_plan(name)
     }
 
-    pub(crate) fn func(&self) -> Option<syn::Path> {
-        if let Some(func_str) = &self.data.func {
-            let method: syn::Path = syn::parse_str(func_str)
-                .unwrap_or_else(|_| panic!("Custom func {} is invalid path!", func_str));
-            Some(method)
-        } else {
-            None
-        }
+    pub(crate) fn to_diff(
+        &self,
+        path: TokenStream2,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.data.to_diff(path, this, other)
+    }
+
+    pub(crate) fn to_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.data.to_lerpify(name, this, other)
+    }
+
+    pub(crate) fn to_try_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.data.to_try_lerpify(name, this, other)
+    }
+
+    pub(crate) fn to_stateful_lerpify(
+        &self,
+        name: &str,
+        this: TokenStream2,
+        other: TokenStream2,
+    ) -> TokenStream2 {
+        self.data.to_stateful_lerpify(name, this, other)
+    }
+
+    pub(crate) fn to_lerp_partial(&self, this: TokenStream2) -> TokenStream2 {
+        self.data.to_lerp_partial(this)
     }
 }
 
@@ -1,6 +1,11 @@
 use std::collections::HashMap;
 
-use lerpable::{IsLerpingMethod, Lerpable};
+use lerpable::{
+    lerp_f32, lerp_f64, try_lerp_int, Degrees, GaussianMutation, Hsva, IsLerpingMethod,
+    LeafCounter, LerpPlan, LerpReport, Lerpable, Mat3, Mat4, Oklch, OnOverflow, Overflow,
+    PathOverride, PathOverrideTable, PathOverrides, Quaternion, Recombination, Rotation2, Rotor3,
+    Srgba, StepAt, StrictLengths, Transform2, Transform3, TryLerpable, UniformCrossover,
+};
 
 #[derive(Debug, Clone, Lerpable)]
 pub struct BasicTypes {
@@ -80,6 +85,305 @@ impl Lerpable for SimpleNewtype {
     }
 }
 
+// when the variant changes, fade out Off (which just pops) and then fade in On
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(fade)]
+enum FadingEnum {
+    Off,
+    On(SimpleNewtype),
+}
+
+#[derive(Debug, Clone, Lerpable)]
+struct RegularPolygon {
+    radius: f32,
+    sides: usize,
+}
+
+// a circle is just a polygon with a lot of sides, so changing between them can lerp
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(cross = "shape_cross")]
+enum Shape {
+    Circle(f32),
+    Polygon(RegularPolygon),
+}
+
+impl Shape {
+    fn to_polygon(&self) -> RegularPolygon {
+        match self {
+            Shape::Circle(radius) => RegularPolygon {
+                radius: *radius,
+                sides: 64,
+            },
+            Shape::Polygon(p) => p.clone(),
+        }
+    }
+}
+
+fn shape_cross<T: IsLerpingMethod>(this: &Shape, other: &Shape, pct: &T) -> Shape {
+    Shape::Polygon(this.to_polygon().lerpify(&other.to_polygon(), pct))
+}
+
+// every mark has a center and a size, so those keep moving while the kind of mark steps
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(shared_fields)]
+enum Mark {
+    Dot {
+        center: f32,
+        size: f32,
+    },
+    Label {
+        center: f32,
+        size: f32,
+        #[lerpable(method = "skip")]
+        text: String,
+    },
+    Nothing,
+}
+
+// going from Small to Huge passes through Medium and Large
+#[derive(Debug, Clone, Copy, Lerpable)]
+#[lerpable(ordinal)]
+enum Size {
+    Small,
+    Medium,
+    Large,
+    Huge = 10,
+}
+
+// a unit vector, lerped by its angle so it stays a unit vector
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(
+    via = "f32",
+    to_via = "Direction::angle",
+    from_via = "Direction::from_angle"
+)]
+struct Direction {
+    x: f32,
+    y: f32,
+}
+
+impl Direction {
+    fn angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    fn from_angle(angle: f32) -> Self {
+        Self {
+            x: angle.cos(),
+            y: angle.sin(),
+        }
+    }
+}
+
+// always sorted, lerped as a plain Vec and sorted again
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(via = "Vec<f32>")]
+struct Sorted(Vec<f32>);
+
+impl From<Vec<f32>> for Sorted {
+    fn from(mut v: Vec<f32>) -> Self {
+        v.sort_by(f32::total_cmp);
+        Sorted(v)
+    }
+}
+
+impl From<Sorted> for Vec<f32> {
+    fn from(s: Sorted) -> Self {
+        s.0
+    }
+}
+
+// probabilities that should add up to 1, and an opacity that should stay in 0..1
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(post = "Self::normalized")]
+struct Weights {
+    weights: Vec<f64>,
+    #[lerpable(clamp(min = 0.0, max = 1.0))]
+    opacity: f64,
+}
+
+impl Weights {
+    fn normalized(mut self) -> Self {
+        let total: f64 = self.weights.iter().sum();
+        if total > 0.0 {
+            self.weights.iter_mut().for_each(|x| *x /= total);
+        }
+        self
+    }
+}
+
+// pretend this is from another crate, so we can't implement Lerpable for it
+mod geometry {
+    #[derive(Debug, Clone)]
+    pub struct Point2 {
+        pub x: f32,
+        pub y: f32,
+    }
+}
+
+// mirrors geometry::Point2's fields, and gets Point2Def::lerpify and friends
+#[derive(Lerpable)]
+#[lerpable(remote = "geometry::Point2")]
+#[allow(dead_code)]
+struct Point2Def {
+    x: f32,
+    #[lerpable(clamp(min = 0.0))]
+    y: f32,
+}
+
+#[derive(Debug, Clone, Lerpable)]
+struct Pin {
+    #[lerpable(with = "Point2Def")]
+    at: geometry::Point2,
+    #[lerpable(with = "grow_in")]
+    radius: f32,
+}
+
+// lerps like usual, but new pins grow in from nothing
+mod grow_in {
+    use lerpable::{IsLerpingMethod, Lerpable};
+
+    pub fn lerpify<T: IsLerpingMethod>(this: &f32, other: &f32, pct: &T) -> f32 {
+        this.lerpify(other, pct)
+    }
+
+    pub fn lerp_partial<T: IsLerpingMethod>(this: &f32, pct: T) -> f32 {
+        this * pct.lerp_pct() as f32
+    }
+}
+
+// the text changes near the start, and the id only at the very end
+#[derive(Debug, Clone, Lerpable)]
+struct Caption {
+    #[lerpable(step_at = 0.1)]
+    text: String,
+    #[lerpable(method = "skip", step_at = 1.0)]
+    id: usize,
+    #[lerpable(keep = "start")]
+    anchor: f32,
+    size: f32,
+}
+
+// as if lerpable were re-exported by a bigger framework
+mod framework {
+    pub use lerpable;
+}
+
+// every field uses custom_method, and the derive finds lerpable through the framework
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(method = "custom_method", crate = "framework::lerpable")]
+struct AllCustom {
+    a: f32,
+    b: Vec<f32>,
+    #[lerpable(method = "skip")]
+    c: String,
+}
+
+// the fields don't mean anything apart, so it switches all at once
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(skip)]
+struct Id {
+    namespace: String,
+    number: u32,
+}
+
+// integers lerp exactly, and each field can round its own way
+#[derive(Debug, Clone, Lerpable)]
+struct Counts {
+    #[lerpable(round = "floor")]
+    whole_items: usize,
+    #[lerpable(round = "ceil")]
+    pages: u16,
+    #[lerpable(round = "stochastic")]
+    particles: u64,
+    id: i64,
+}
+
+// keyframes someone typed in, checked with try_lerpify before rendering
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(fallible)]
+struct Vertex {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(fallible)]
+enum Outline {
+    Circle { radius: f32 },
+    Polygon(Vec<Vertex>),
+    Hidden,
+}
+
+#[derive(Debug, Clone, Lerpable)]
+#[lerpable(fallible)]
+struct Keyframe {
+    label: String,
+    outline: Outline,
+    #[lerpable(overflow = "checked")]
+    repeats: u8,
+}
+
+// rotations go the short way around, unless asked otherwise
+#[derive(Debug, Clone, Lerpable)]
+struct Spinner {
+    heading: Degrees,
+    #[lerpable(angle)]
+    tilt: f32,
+    #[lerpable(angle(degrees, direction = "ccw", turns = 1))]
+    spin: f64,
+}
+
+// scale-like values look better spaced evenly in log space
+#[derive(Debug, Clone, Lerpable)]
+struct Zoom {
+    #[lerpable(log)]
+    level: f32,
+    #[lerpable(log)]
+    octaves: Vec<f64>,
+    #[lerpable(log)]
+    focus: Option<f32>,
+    offset: f32,
+}
+
+// colors lerp in perceptual spaces, with alpha premultiplied
+#[derive(Debug, Clone, Lerpable)]
+struct Swatch {
+    fill: Srgba,
+    #[lerpable(with = "lerpable::color::oklch")]
+    stroke: Srgba,
+    glow: Oklch,
+    highlights: Vec<Hsva>,
+}
+
+// rotations slerp the shortest way around, at a constant angular velocity
+#[derive(Debug, Clone, Lerpable)]
+struct Gimbal {
+    orientation: Quaternion,
+    #[lerpable(with = "lerpable::rotation::nlerp")]
+    wobble: Quaternion,
+    spin: Rotor3,
+    heading: Rotation2,
+}
+
+// transforms split into translation, rotation, scale, and shear to lerp
+#[derive(Debug, Clone, Lerpable)]
+struct Stage {
+    camera: Transform3,
+    prop: Mat4,
+    sticker: Mat3,
+}
+
+// what happens when a pct outside 0 to 1 goes past the type
+#[derive(Debug, Clone, Lerpable)]
+struct Dial {
+    #[lerpable(overflow = "wrap")]
+    hue: u8,
+    #[lerpable(overflow = "clamp")]
+    level: i8,
+    letter: char,
+}
+
 #[derive(Debug, Clone, Lerpable)]
 struct OverrideNewtype(#[lerpable(func = "custom_func")] f32);
 
@@ -92,4 +396,378 @@ fn main() {
         b: HashMap::new(),
     });
     a.lerpify(&b, &0.75);
+
+    let c = EnumTest::C(UnLerpableType());
+    c.lerpify(&c, &0.5);
+
+    let simple = SimpleNewtype(1.0).lerpify(&SimpleNewtype(2.0), &0.5);
+    println!("{}", simple.0);
+    OverrideNewtype(1.0).lerpify(&OverrideNewtype(2.0), &0.5);
+
+    // step every `a_number`, wherever it is, but lerp everything else
+    let table = PathOverrideTable::new()
+        .with("**.a_number", PathOverride::Step)
+        .unwrap();
+    let start = BasicTypes {
+        s: "start".to_owned(),
+        a_number: 0.0,
+        b_number: 0,
+        something: vec![0.0, 0.0],
+    };
+    let end = BasicTypes {
+        s: "end".to_owned(),
+        a_number: 10.0,
+        b_number: 10,
+        something: vec![10.0, 10.0],
+    };
+    let quarter = start.lerpify(&end, &PathOverrides::new(table, 0.25));
+    println!("{:?}", quarter);
+
+    // what's going to happen?
+    println!("{}", LerpPlan::of::<EnumTest>());
+    print!("{}", LerpReport::between(&start, &end));
+
+    // a genetic algorithm step: pick each leaf from one parent, then nudge the numbers
+    let child = start.lerpify(
+        &end,
+        &GaussianMutation::new(UniformCrossover::new(42), 7, 0.5).with_rate(0.2),
+    );
+    println!("{:?}", child);
+
+    // or thread a stateful method through, in field order
+    let mut crossover = UniformCrossover::new(42);
+    let child = start.lerpify_mut(&end, &mut crossover);
+    println!("{:?}", child);
+
+    let mut counter = LeafCounter::new(0.5);
+    b.lerpify_mut(&b, &mut counter);
+    println!("{} leaves", counter.count());
+
+    // combine a few parents at once
+    let middle = start.lerpify(&end, &0.5);
+    let parents = [start.clone(), middle, end.clone()];
+    let child = lerpable::recombine(&parents, &[1.0, 2.0, 1.0], Recombination::WeightedMean, 3);
+    println!("{:?}", child);
+
+    let fading = FadingEnum::Off.lerpify(&FadingEnum::On(SimpleNewtype(1.0)), &0.75);
+    println!("{:?}", fading);
+
+    let triangle = Shape::Polygon(RegularPolygon {
+        radius: 2.0,
+        sides: 3,
+    });
+    println!("{:?}", Shape::Circle(1.0).lerpify(&triangle, &0.5));
+
+    let dot = Mark::Dot {
+        center: 0.0,
+        size: 1.0,
+    };
+    let label = Mark::Label {
+        center: 10.0,
+        size: 2.0,
+        text: "hi".to_owned(),
+    };
+    println!("{:?}", dot.lerpify(&label, &0.75));
+    println!("{:?}", dot.lerpify(&Mark::Nothing, &0.75));
+    print!("{}", LerpReport::between(&dot, &label));
+
+    for pct in [0.0, 0.1, 0.2, 0.5, 1.0] {
+        println!("{:?}", Size::Small.lerpify(&Size::Huge, &pct));
+    }
+    println!("{:?}", Size::Medium.lerpify(&Size::Large, &0.5));
+
+    let right = Direction::from_angle(0.0);
+    let up = Direction::from_angle(std::f32::consts::FRAC_PI_2);
+    println!("{:?}", right.lerpify(&up, &0.5));
+    print!("{}", LerpPlan::of::<Direction>());
+
+    let sorted = Sorted::from(vec![3.0, 1.0, 2.0]);
+    let reversed = Sorted::from(vec![0.0, 10.0]);
+    println!("{:?}", sorted.lerpify(&reversed, &0.5));
+    println!("{:?}", sorted.lerp_partial(0.5));
+
+    let even = Weights {
+        weights: vec![0.5, 0.5],
+        opacity: 0.5,
+    };
+    let lopsided = Weights {
+        weights: vec![0.9, 0.3],
+        opacity: 1.0,
+    };
+    println!("{:?}", even.lerpify(&lopsided, &1.5));
+
+    let pin = Pin {
+        at: geometry::Point2 { x: 0.0, y: 1.0 },
+        radius: 1.0,
+    };
+    let other_pin = Pin {
+        at: geometry::Point2 { x: 2.0, y: 3.0 },
+        radius: 2.0,
+    };
+    println!("{:?}", pin.lerpify(&other_pin, &-1.0));
+    let new_pin = vec![pin.clone()].lerpify(&vec![pin, other_pin], &0.75);
+    println!("{:?}", new_pin);
+    print!("{}", Point2Def::lerp_plan());
+
+    let hello = Caption {
+        text: "hello".to_owned(),
+        id: 1,
+        anchor: 0.0,
+        size: 10.0,
+    };
+    let goodbye = Caption {
+        text: "goodbye".to_owned(),
+        id: 2,
+        anchor: 5.0,
+        size: 20.0,
+    };
+    for pct in [0.05, 0.5, 1.0] {
+        println!("{:?}", hello.lerpify(&goodbye, &pct));
+    }
+    // or for everything at once
+    println!("{:?}", hello.lerpify(&goodbye, &StepAt::new(0.2, 0.0)));
+
+    let custom = AllCustom {
+        a: 1.0,
+        b: vec![1.0],
+        c: "start".to_owned(),
+    };
+    let other_custom = AllCustom {
+        a: 2.0,
+        b: vec![2.0],
+        c: "end".to_owned(),
+    };
+    println!("{:?}", custom.lerpify(&other_custom, &0.5));
+    print!("{}", LerpPlan::of::<AllCustom>());
+
+    let id = Id {
+        namespace: "a".to_owned(),
+        number: 1,
+    };
+    let other_id = Id {
+        namespace: "b".to_owned(),
+        number: 2,
+    };
+    let id = id.lerpify(&other_id, &0.75);
+    println!("{}:{}", id.namespace, id.number);
+
+    let few = Counts {
+        whole_items: 1,
+        pages: 1,
+        particles: 10,
+        id: (1 << 60) + 1,
+    };
+    let many = Counts {
+        whole_items: 2,
+        pages: 2,
+        particles: 11,
+        id: (1 << 60) + 4,
+    };
+    for pct in [0.0, 0.25, 0.5, 1.0] {
+        println!("{:?}", few.lerpify(&many, &pct));
+    }
+    println!("{}", (u64::MAX - 1).lerpify(&u64::MAX, &0.5));
+
+    for policy in [
+        Overflow::Saturate,
+        Overflow::Wrap,
+        Overflow::Clamp,
+        Overflow::Checked,
+    ] {
+        let pct = OnOverflow::new(2.0, policy);
+        println!(
+            "{:?}: {} {:?}",
+            policy,
+            100u8.lerpify(&200, &pct),
+            try_lerp_int(100u8, 200, &pct)
+        );
+    }
+    if let Err(err) = try_lerp_int(0i128, i128::MAX, &OnOverflow::new(-2.0, Overflow::Checked)) {
+        println!("{}", err);
+    }
+
+    let dial = Dial {
+        hue: 200,
+        level: 10,
+        letter: 'a',
+    };
+    let other_dial = Dial {
+        hue: 250,
+        level: 20,
+        letter: 'z',
+    };
+    for pct in [0.5, 2.0] {
+        println!("{:?}", dial.lerpify(&other_dial, &pct));
+    }
+
+    let square = |size: f32| {
+        Outline::Polygon(vec![
+            Vertex { x: 0.0, y: 0.0 },
+            Vertex { x: size, y: 0.0 },
+            Vertex { x: size, y: size },
+        ])
+    };
+    let keyframe = |outline: Outline, repeats: u8| Keyframe {
+        label: "key".to_owned(),
+        outline,
+        repeats,
+    };
+    let start = keyframe(square(1.0), 100);
+    let checks = [
+        ("ok", keyframe(square(2.0), 200), 0.5),
+        ("variant", keyframe(Outline::Hidden, 200), 0.5),
+        ("overflow", keyframe(square(2.0), 200), 2.0),
+        (
+            "nan",
+            keyframe(
+                Outline::Polygon(vec![
+                    Vertex {
+                        x: f32::NAN,
+                        y: 0.0
+                    };
+                    3
+                ]),
+                100,
+            ),
+            0.5,
+        ),
+    ];
+    for (name, end, pct) in checks {
+        match start.try_lerpify(&end, &pct) {
+            Ok(keyframe) => println!("{}: {:?}", name, keyframe),
+            Err(err) => println!("{}: {}", name, err),
+        }
+    }
+    let circle = keyframe(Outline::Circle { radius: 1.0 }, 1);
+    let _ = circle.try_lerpify(&circle, &0.5).unwrap();
+    let fewer = keyframe(Outline::Polygon(vec![Vertex { x: 0.0, y: 0.0 }]), 100);
+    println!("{:?}", start.try_lerpify(&fewer, &0.5).map(|x| x.outline));
+    if let Err(err) = start.try_lerpify(&fewer, &StrictLengths::new(0.5)) {
+        println!("strict: {}", err);
+    }
+
+    // floats are exact at the ends, even with infinities
+    println!(
+        "{} {} {} {}",
+        f64::INFINITY.lerpify(&0.0, &0.0),
+        f64::INFINITY.lerpify(&0.0, &0.5),
+        0.1f64.lerpify(&0.7, &1.0) == 0.7,
+        lerp_f64(-1e300, 1e300, 0.5),
+    );
+
+    // f32s stay in single precision, especially with an f32 pct
+    let buffer = (0..4).map(|x| x as f32 * 0.1).collect::<Vec<_>>();
+    let reversed = buffer.iter().rev().copied().collect::<Vec<_>>();
+    println!("{:?}", buffer.lerpify(&reversed, &0.25f32));
+    println!(
+        "{} {}",
+        lerp_f32(0.1, 0.7, 0.3),
+        lerp_f64(0.1, 0.7, 0.3) as f32
+    );
+
+    let spinner = Spinner {
+        heading: Degrees(350.0),
+        tilt: 0.1,
+        spin: 90.0,
+    };
+    let other_spinner = Spinner {
+        heading: Degrees(10.0),
+        tilt: std::f32::consts::TAU - 0.1,
+        spin: 0.0,
+    };
+    for pct in [0.0, 0.25, 0.5, 1.0] {
+        println!("{:?}", spinner.lerpify(&other_spinner, &pct));
+    }
+    println!("{}", LerpPlan::of::<Spinner>());
+
+    let zoom = Zoom {
+        level: 1.0,
+        octaves: vec![110.0, 220.0],
+        focus: Some(0.5),
+        offset: 1.0,
+    };
+    let other_zoom = Zoom {
+        level: 100.0,
+        octaves: vec![440.0, 0.0],
+        focus: Some(8.0),
+        offset: 100.0,
+    };
+    println!("{:?}", zoom.lerpify(&other_zoom, &0.5));
+    println!("{}", LerpPlan::of::<Zoom>());
+
+    let swatch = Swatch {
+        fill: Srgba::rgb(1.0, 0.0, 0.0),
+        stroke: Srgba::rgb(1.0, 0.0, 0.0),
+        glow: Oklch::new(0.7, 0.1, 350.0, 1.0),
+        highlights: vec![Hsva::new(0.0, 1.0, 1.0, 1.0)],
+    };
+    let other_swatch = Swatch {
+        fill: Srgba::rgb(0.0, 1.0, 1.0),
+        stroke: Srgba::rgb(0.0, 1.0, 1.0),
+        glow: Oklch::new(0.7, 0.1, 10.0, 0.0),
+        highlights: vec![
+            Hsva::new(240.0, 1.0, 1.0, 1.0),
+            Hsva::new(120.0, 1.0, 1.0, 1.0),
+        ],
+    };
+    println!("{:?}", swatch.lerpify(&other_swatch, &0.5));
+
+    let gimbal = Gimbal {
+        orientation: Quaternion::IDENTITY,
+        wobble: Quaternion::IDENTITY,
+        spin: Rotor3::IDENTITY,
+        heading: Rotation2::from_angle(3.0),
+    };
+    let other_gimbal = Gimbal {
+        orientation: Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0),
+        wobble: Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0),
+        spin: Rotor3::from_angle_plane(3.0, [1.0, 0.0, 0.0]),
+        heading: Rotation2::from_angle(-3.0),
+    };
+    println!("{:?}", gimbal.lerpify(&other_gimbal, &0.5));
+    // slerp turns the same amount each step, nlerp speeds up in the middle
+    for pct in [0.25, 0.5, 0.75] {
+        let g = gimbal.lerpify(&other_gimbal, &pct);
+        println!(
+            "{pct}: slerp {:.4}, nlerp {:.4}, rotor {:.4} (want {:.4}), heading {:.4} (want {:.4})",
+            gimbal.orientation.angle_to(&g.orientation),
+            gimbal.wobble.angle_to(&g.wobble),
+            gimbal.spin.angle_to(&g.spin),
+            pct * 3.0,
+            gimbal.heading.angle_to(&g.heading),
+            // 3 to -3 goes the short way, through PI
+            pct * gimbal.heading.angle_to(&other_gimbal.heading),
+        );
+    }
+
+    let spun = Transform3::new(
+        [0.0, 0.0, 0.0],
+        Quaternion::from_axis_angle([0.0, 0.0, 1.0], 3.0),
+        [2.0, 2.0, 2.0],
+    );
+    let stage = Stage {
+        camera: Transform3::IDENTITY,
+        prop: Mat4::IDENTITY,
+        sticker: Mat3::IDENTITY,
+    };
+    let other_stage = Stage {
+        camera: spun.with_shear([0.5, 0.0, 0.0]),
+        prop: spun.into(),
+        // mirrored in x, which comes back as a negative y scale with a half turn
+        sticker: Transform2::new([10.0, 0.0], Rotation2::IDENTITY, [-1.0, 1.0]).into(),
+    };
+    let halfway = stage.lerpify(&other_stage, &0.5);
+    println!("{:?}", halfway);
+    // the prop stays the same size the whole way, instead of shrinking in the middle
+    println!("{:?}", Transform3::from(halfway.prop));
+    println!("{:?}", Transform2::from(halfway.sticker));
+    // squashed flat, which still splits up the same way every time
+    let flat = Mat4([
+        [1.0, 0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [1.0, 2.0, 3.0, 1.0],
+    ]);
+    println!("{:?}", Transform3::from(flat));
+    println!("{:?}", flat.lerpify(&Mat4::IDENTITY, &0.5));
 }